@@ -0,0 +1,174 @@
+use std::{fmt::Display, num::ParseIntError, path::PathBuf};
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Parse(ParseError),
+}
+
+impl Error {
+    /// Human readable report, including the offending source line for parse errors.
+    pub fn diagnostic(&self) -> String {
+        match self {
+            Error::Io { .. } => format!("error: {self}"),
+            Error::Parse(err) => err.diagnostic(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "failed to read {}: {source}", path.display()),
+            Error::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(value: ParseError) -> Self {
+        Error::Parse(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    pub source_line: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Builds an error pointing at `span`, which must be a slice of `input`.
+    pub fn new(day: u8, input: &str, span: &str, message: impl Into<String>) -> Self {
+        let offset = offset_in(input, span);
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let line_number = input[..offset].matches('\n').count() + 1;
+        Self::on_line(
+            day,
+            line_number,
+            &input[line_start..line_end],
+            span,
+            message,
+        )
+    }
+
+    /// Builds an error pointing at `span` within `line`, for input that is read a
+    /// line at a time rather than held in memory.
+    pub fn on_line(
+        day: u8,
+        line_number: usize,
+        line: &str,
+        span: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        let offset = offset_in(line, span);
+        ParseError {
+            day,
+            line: line_number,
+            column: line[..offset].chars().count() + 1,
+            text: span.to_string(),
+            source_line: line.trim_end_matches('\r').to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn diagnostic(&self) -> String {
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let padding = " ".repeat(self.column - 1);
+        let carets = "^".repeat(self.text.chars().count().max(1));
+        format!(
+            "error: {}\n{gutter}--> day {}, line {}, column {}\n{gutter} |\n{line_number} | {}\n{gutter} | {padding}{carets}",
+            self.message, self.day, self.line, self.column, self.source_line
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Per-line parse errors that can point at the part of the line they reject.
+pub trait LineError: Display {
+    fn span<'a>(&self, line: &'a str) -> &'a str {
+        line
+    }
+}
+
+impl LineError for ParseIntError {}
+
+fn offset_in(input: &str, span: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let offset = (span.as_ptr() as usize).wrapping_sub(start);
+    assert!(
+        offset <= input.len() && offset + span.len() <= input.len(),
+        "span should be a slice of the input"
+    );
+    offset
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_position() {
+        let input = "L68\nL30\nX48\n";
+        let err = ParseError::new(1, input, &input[8..9], "invalid direction");
+        assert_eq!(err.line, 3);
+        assert_eq!(err.column, 1);
+        assert_eq!(err.text, "X");
+        assert_eq!(err.source_line, "X48");
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let input = "3-5\n10-1x\n";
+        let err = ParseError::new(5, input, &input[7..9], "invalid number");
+        assert_eq!(
+            err.diagnostic(),
+            "error: invalid number\n --> day 5, line 2, column 4\n  |\n2 | 10-1x\n  |    ^^"
+        );
+    }
+
+    #[test]
+    fn test_on_line_matches_new() {
+        let input = "L68\nL30\nX48\n";
+        let line = &input[8..11];
+        let err = ParseError::on_line(1, 3, line, &line[..1], "invalid direction");
+        assert_eq!(
+            err,
+            ParseError::new(1, input, &input[8..9], "invalid direction")
+        );
+    }
+}
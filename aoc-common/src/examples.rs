@@ -0,0 +1,40 @@
+/// Embeds a file from the calling crate's directory at compile time, so tests
+/// don't depend on the working directory `cargo test` is run from.
+#[macro_export]
+macro_rules! example_file {
+    ($path:literal) => {
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $path))
+    };
+}
+
+/// Declares a table of example tests for a [`Solution`](crate::Solution), one
+/// `#[test]` per row of `name: input, part => expected`.
+///
+/// ```ignore
+/// aoc_common::examples! {
+///     Day1 {
+///         example_part1: example_file!("test_input.txt"), part1 => 3,
+///         full_turns: "L50\nR100\n", part2 => 2,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($solution:ty { $($name:ident: $input:expr, $part:ident => $expected:expr),* $(,)? }) => {
+        #[cfg(test)]
+        mod examples {
+            #[allow(unused_imports)]
+            use super::*;
+            #[allow(unused_imports)]
+            use $crate::{Solution, example_file};
+
+            $(
+                #[test]
+                fn $name() {
+                    let input = <$solution as Solution>::parse($input).expect("example should parse");
+                    assert_eq!(<$solution as Solution>::$part(&input), $expected);
+                }
+            )*
+        }
+    };
+}
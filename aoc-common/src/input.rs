@@ -0,0 +1,103 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Read},
+    path::Path,
+    str::FromStr,
+};
+
+use crate::{Error, LineError, ParseError};
+
+pub const STDIN_PATH: &str = "-";
+
+/// Reads the puzzle input, treating `-` as stdin.
+pub fn read_input(filename: &Path) -> Result<String, Error> {
+    let to_error = |source| Error::Io {
+        path: filename.to_path_buf(),
+        source,
+    };
+    if filename != Path::new(STDIN_PATH) {
+        return std::fs::read_to_string(filename).map_err(to_error);
+    }
+    let mut text = String::new();
+    std::io::stdin()
+        .read_to_string(&mut text)
+        .map_err(to_error)?;
+    Ok(text)
+}
+
+/// Opens the puzzle input for reading a line at a time, treating `-` as stdin.
+pub fn open_input(filename: &Path) -> Result<Box<dyn BufRead>, Error> {
+    if filename == Path::new(STDIN_PATH) {
+        return Ok(Box::new(std::io::stdin().lock()));
+    }
+    let file = File::open(filename).map_err(|source| Error::Io {
+        path: filename.to_path_buf(),
+        source,
+    })?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// Lines of the input, accepting both `\n` and `\r\n` endings.
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines()
+}
+
+/// Blank-line separated sections of the input, accepting the same line endings
+/// as [`lines`]. Sections are slices of `text`, so errors can point into them.
+pub fn sections(text: &str) -> impl Iterator<Item = &str> {
+    let mut lines = lines(text).peekable();
+    let offset = move |line: &str| line.as_ptr() as usize - text.as_ptr() as usize;
+    std::iter::from_fn(move || {
+        let first = lines.by_ref().find(|line| !line.is_empty())?;
+        let mut last = first;
+        while let Some(line) = lines.next_if(|line| !line.is_empty()) {
+            last = line;
+        }
+        Some(&text[offset(first)..offset(last) + last.len()])
+    })
+}
+
+/// Parses every line of the input, pointing any error at the line that failed.
+pub fn parse_lines<T>(day: u8, input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: LineError,
+{
+    lines(input)
+        .map(|line| parse_line(day, input, line))
+        .collect()
+}
+
+/// Parses one line of `input`, pointing any error at the part of the line that failed.
+pub fn parse_line<T>(day: u8, input: &str, line: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: LineError,
+{
+    line.parse()
+        .map_err(|err: T::Err| ParseError::new(day, input, err.span(line), err.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let text = "1-2\n3-4\n\n5\n6\n";
+        let split: Vec<&str> = sections(text).collect();
+        assert_eq!(split, vec!["1-2\n3-4", "5\n6"]);
+
+        let text = "1-2\r\n3-4\r\n\r\n\r\n5\r\n6\r\n";
+        let split: Vec<&str> = sections(text).collect();
+        assert_eq!(split, vec!["1-2\r\n3-4", "5\r\n6"]);
+        assert_eq!(split[1].lines().collect::<Vec<_>>(), ["5", "6"]);
+    }
+
+    #[test]
+    fn test_parse_lines_reports_line() {
+        let err = parse_lines::<u64>(1, "1\n2\nx\n").unwrap_err();
+        assert_eq!(err.line, 3);
+        assert_eq!(err.text, "x");
+    }
+}
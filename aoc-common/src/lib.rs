@@ -0,0 +1,10 @@
+mod error;
+mod examples;
+pub mod generate;
+mod input;
+mod solution;
+pub mod stats;
+
+pub use error::{Error, LineError, ParseError};
+pub use input::{STDIN_PATH, lines, open_input, parse_line, parse_lines, read_input, sections};
+pub use solution::{Part, Solution, run};
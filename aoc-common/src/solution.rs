@@ -0,0 +1,79 @@
+use std::{fmt::Display, path::Path, process::ExitCode};
+
+use crate::{Error, ParseError, read_input};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("invalid part: {value}")),
+        }
+    }
+}
+
+/// A day's puzzle, split so the input is parsed once and shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    fn parse_file(filename: &Path) -> Result<Self::Input, Error> {
+        Ok(Self::parse(&read_input(filename)?)?)
+    }
+
+    fn solve(input: &Self::Input, part: Part) -> String {
+        match part {
+            Part::One => Self::part1(input).to_string(),
+            Part::Two => Self::part2(input).to_string(),
+        }
+    }
+}
+
+/// Entry point shared by the per-day binaries.
+pub fn run<S: Solution>(filename: &Path) -> ExitCode {
+    let input = match S::parse_file(filename) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err.diagnostic());
+            return ExitCode::FAILURE;
+        }
+    };
+    for part in Part::ALL {
+        println!("Part {part}: {}", S::solve(&input, part));
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,61 @@
+//! Parse, part 1 and part 2 timings for every day, on the example and (when
+//! present) the real input.
+//!
+//! Save a baseline with `cargo bench-save` and compare a change against it
+//! with `cargo bench-compare`.
+
+use std::{hint::black_box, path::PathBuf};
+
+use aoc_common::Solution;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+const YEAR: &str = "2025";
+
+/// The fetched input from the cache, falling back to the day's own `input.txt`.
+fn real_input(day: u8) -> Option<String> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let cache_dir = std::env::var_os("AOC_CACHE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root.join("inputs"));
+    [
+        cache_dir
+            .join(YEAR)
+            .join(format!("day{day:02}"))
+            .join("input.txt"),
+        root.join(format!("day{day}")).join("input.txt"),
+    ]
+    .iter()
+    .find_map(|path| std::fs::read_to_string(path).ok())
+}
+
+fn bench_input<S: Solution>(c: &mut Criterion, name: &str, text: &str) {
+    let mut group = c.benchmark_group(format!("day{}/{name}", S::DAY));
+    if name == "real" {
+        group.sample_size(10);
+    }
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(text))));
+    let input = S::parse(text).expect("input should parse");
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input))));
+    group.finish();
+}
+
+fn bench_solution<S: Solution>(c: &mut Criterion, example: &str) {
+    bench_input::<S>(c, "example", example);
+    if let Some(text) = real_input(S::DAY) {
+        bench_input::<S>(c, "real", &text);
+    }
+}
+
+fn days(c: &mut Criterion) {
+    bench_solution::<day1::Day1>(c, include_str!("../../day1/test_input.txt"));
+    bench_solution::<day2::Day2>(c, include_str!("../../day2/test_input.txt"));
+    bench_solution::<day3::Day3>(c, include_str!("../../day3/test_input.txt"));
+    bench_solution::<day4::Day4>(c, include_str!("../../day4/test_input.txt"));
+    bench_solution::<day5::Day5>(c, include_str!("../../day5/test_input.txt"));
+    bench_solution::<day6::Day6>(c, include_str!("../../day6/test_input.txt"));
+    bench_solution::<day7::Day7>(c, include_str!("../../day7/test_input.txt"));
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,93 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::Part;
+
+pub const DEFAULT_ANSWERS: &str = "answers.txt";
+
+/// Verified answers for our real inputs, as `day part answer` lines.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, std::io::Error),
+    BadLine(PathBuf, usize, String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io(path, err) => write!(f, "failed to read {}: {err}", path.display()),
+            AnswersError::BadLine(path, line, text) => {
+                write!(f, "{}:{line}: invalid answer line '{text}'", path.display())
+            }
+        }
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| AnswersError::Io(path.to_path_buf(), err))?;
+        Answers::parse(path, &text)
+    }
+
+    fn parse(path: &Path, text: &str) -> Result<Self, AnswersError> {
+        let mut answers = Answers::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let bad_line = || AnswersError::BadLine(path.to_path_buf(), i + 1, line.to_string());
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [day, part, answer] = fields[..] else {
+                return Err(bad_line());
+            };
+            let day = day.parse().map_err(|_| bad_line())?;
+            let part = part
+                .parse::<u8>()
+                .ok()
+                .and_then(|part| Part::try_from(part).ok())
+                .ok_or_else(bad_line)?;
+            answers.answers.insert((day, part), answer.to_string());
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = "# day part answer\n1 1 1034\n1 2 6166\n\n7 2 12345678901234\n";
+        let answers = Answers::parse(Path::new(DEFAULT_ANSWERS), text).unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("1034"));
+        assert_eq!(answers.get(7, Part::Two), Some("12345678901234"));
+        assert_eq!(answers.get(7, Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_bad_line() {
+        let err = Answers::parse(Path::new(DEFAULT_ANSWERS), "1 3 1034\n").unwrap_err();
+        assert!(matches!(err, AnswersError::BadLine(_, 1, _)));
+    }
+
+    #[test]
+    fn test_checked_in_answers_parse() {
+        let text = include_str!("../../answers.txt");
+        assert!(Answers::parse(Path::new(DEFAULT_ANSWERS), text).is_ok());
+    }
+}
@@ -0,0 +1,95 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::config::Config;
+
+const USER_AGENT: &str = concat!(
+    "github.com/jmckinny/advent-of-code-2025 aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Status(u16, String),
+    Transport(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session token, set AOC_SESSION or `session` in the config file"
+            ),
+            ClientError::Status(status, url) => write!(f, "{url} returned HTTP {status}"),
+            ClientError::Transport(err) => write!(f, "request failed: {err}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(value: ureq::Error) -> Self {
+        match value {
+            ureq::Error::Status(status, response) => {
+                ClientError::Status(status, response.get_url().to_string())
+            }
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for ClientError {
+    fn from(value: std::io::Error) -> Self {
+        ClientError::Transport(value.to_string())
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, ClientError> {
+        let session = config.session.clone().ok_or(ClientError::MissingSession)?;
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(TIMEOUT)
+            .build();
+        Ok(Client {
+            agent,
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            session,
+        })
+    }
+
+    fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}", self.base_url)
+    }
+
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(&format!("{}/input", self.day_url(year, day)))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        level: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(year, day)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level.to_string()), ("answer", answer)])?;
+        Ok(response.into_string()?)
+    }
+}
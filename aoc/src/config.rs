@@ -0,0 +1,150 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_CACHE_DIR: &str = "inputs";
+pub const DEFAULT_YEAR: u16 = 2025;
+
+const SESSION_VAR: &str = "AOC_SESSION";
+const BASE_URL_VAR: &str = "AOC_BASE_URL";
+const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Settings for talking to the website, read from the config file and then
+/// overridden by `AOC_*` environment variables.
+///
+/// The config file defaults to `$XDG_CONFIG_HOME/aoc/config` (or `~/.config/aoc/config`)
+/// and holds `key = value` lines for `session`, `base_url` and `cache_dir`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub cache_dir: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, std::io::Error),
+    BadLine(PathBuf, usize, String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(path, err) => write!(f, "failed to read {}: {err}", path.display()),
+            ConfigError::BadLine(path, line, text) => {
+                write!(f, "{}:{line}: invalid config line '{text}'", path.display())
+            }
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            cache_dir: PathBuf::from(DEFAULT_CACHE_DIR),
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> Result<Config, ConfigError> {
+        let mut config = Config::default();
+        if let Some(path) = config_path() {
+            match std::fs::read_to_string(&path) {
+                Ok(text) => config.apply_file(&path, &text)?,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(ConfigError::Io(path, err)),
+            }
+        }
+        if let Ok(session) = std::env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var(BASE_URL_VAR) {
+            config.base_url = base_url;
+        }
+        if let Some(cache_dir) = std::env::var_os(CACHE_DIR_VAR) {
+            config.cache_dir = PathBuf::from(cache_dir);
+        }
+        Ok(config)
+    }
+
+    fn apply_file(&mut self, path: &Path, text: &str) -> Result<(), ConfigError> {
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let bad_line = || ConfigError::BadLine(path.to_path_buf(), i + 1, line.to_string());
+            let (key, value) = line.split_once('=').ok_or_else(bad_line)?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "session" => self.session = Some(value),
+                "base_url" => self.base_url = value,
+                "cache_dir" => self.cache_dir = PathBuf::from(value),
+                _ => return Err(bad_line()),
+            }
+        }
+        Ok(())
+    }
+
+    pub fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{day:02}"))
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("input.txt")
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(CONFIG_VAR) {
+        return Some(PathBuf::from(path));
+    }
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_home.join("aoc").join("config"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_apply_file() {
+        let mut config = Config::default();
+        let text = "# personal settings\nsession = abc123\n\nbase_url = http://localhost:8080\n";
+        config.apply_file(Path::new("config"), text).unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.cache_dir, PathBuf::from(DEFAULT_CACHE_DIR));
+    }
+
+    #[test]
+    fn test_apply_file_bad_line() {
+        let mut config = Config::default();
+        let err = config
+            .apply_file(Path::new("config"), "session abc123\n")
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::BadLine(_, 1, _)));
+    }
+
+    #[test]
+    fn test_input_path() {
+        let config = Config::default();
+        assert_eq!(
+            config.input_path(2025, 5),
+            Path::new("inputs")
+                .join("2025")
+                .join("day05")
+                .join("input.txt")
+        );
+    }
+}
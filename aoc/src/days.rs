@@ -0,0 +1,100 @@
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{
+    Error, ParseError, Part, Solution,
+    generate::{Rng, Size},
+    stats::{self, Usage},
+};
+
+use crate::config::{Config, DEFAULT_YEAR};
+
+pub struct PartRun {
+    pub part: Part,
+    pub answer: String,
+    pub usage: Usage,
+}
+
+pub struct DayRun {
+    pub parse: Usage,
+    pub parts: Vec<PartRun>,
+}
+
+impl DayRun {
+    pub fn answers(self) -> Vec<String> {
+        self.parts.into_iter().map(|part| part.answer).collect()
+    }
+}
+
+type SolveFn = fn(&str, &[Part]) -> Result<DayRun, ParseError>;
+type GenerateFn = fn(&mut dyn Write, &mut Rng, Size) -> io::Result<()>;
+
+pub struct Day {
+    pub number: u8,
+    solve: SolveFn,
+    generate: GenerateFn,
+}
+
+impl Day {
+    pub fn run(&self, input: &Path, parts: &[Part]) -> Result<DayRun, Error> {
+        let text = aoc_common::read_input(input)?;
+        Ok((self.solve)(&text, parts)?)
+    }
+
+    /// The fetched input when it is cached, otherwise the day's own `input.txt`.
+    pub fn default_input(&self, config: &Config) -> PathBuf {
+        let cached = config.input_path(DEFAULT_YEAR, self.number);
+        if cached.exists() {
+            return cached;
+        }
+        Path::new(&format!("day{}", self.number)).join("input.txt")
+    }
+
+    /// Writes a random input in the day's format, the same for every run with `seed`.
+    pub fn generate(&self, out: &mut dyn Write, seed: u64, size: Size) -> io::Result<()> {
+        (self.generate)(out, &mut Rng::new(seed), size)
+    }
+}
+
+fn solve<S: Solution>(text: &str, parts: &[Part]) -> Result<DayRun, ParseError> {
+    let (input, parse) = stats::measure(|| S::parse(text));
+    let input = input?;
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let (answer, usage) = stats::measure(|| S::solve(&input, part));
+            PartRun {
+                part,
+                answer,
+                usage,
+            }
+        })
+        .collect();
+    Ok(DayRun { parse, parts })
+}
+
+macro_rules! day {
+    ($krate:ident :: $solution:ident) => {
+        Day {
+            number: <$krate::$solution as Solution>::DAY,
+            solve: solve::<$krate::$solution>,
+            generate: $krate::generate,
+        }
+    };
+}
+
+pub const DAYS: [Day; 7] = [
+    day!(day1::Day1),
+    day!(day2::Day2),
+    day!(day3::Day3),
+    day!(day4::Day4),
+    day!(day5::Day5),
+    day!(day6::Day6),
+    day!(day7::Day7),
+];
+
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
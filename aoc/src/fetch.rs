@@ -0,0 +1,48 @@
+use std::{fmt::Display, path::PathBuf};
+
+use crate::{
+    client::{Client, ClientError},
+    config::Config,
+};
+
+#[derive(Debug)]
+pub enum FetchError {
+    Client(ClientError),
+    Io(PathBuf, std::io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Client(err) => write!(f, "{err}"),
+            FetchError::Io(path, err) => write!(f, "failed to write {}: {err}", path.display()),
+        }
+    }
+}
+
+impl From<ClientError> for FetchError {
+    fn from(value: ClientError) -> Self {
+        FetchError::Client(value)
+    }
+}
+
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input for a day unless it is already in the cache.
+pub fn fetch_input(config: &Config, year: u16, day: u8) -> Result<Fetched, FetchError> {
+    let path = config.input_path(year, day);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+    let input = Client::new(config)?.fetch_input(year, day)?;
+
+    let dir = config.day_dir(year, day);
+    std::fs::create_dir_all(&dir).map_err(|err| FetchError::Io(dir, err))?;
+    let partial = path.with_extension("txt.partial");
+    std::fs::write(&partial, input).map_err(|err| FetchError::Io(partial.clone(), err))?;
+    std::fs::rename(&partial, &path).map_err(|err| FetchError::Io(path.clone(), err))?;
+    Ok(Fetched::Downloaded(path))
+}
@@ -0,0 +1,387 @@
+mod answers;
+mod client;
+mod config;
+mod days;
+mod fetch;
+mod submit;
+mod table;
+mod verify;
+
+use std::{
+    io::{self, BufWriter, ErrorKind, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use aoc_common::{Part, generate::Size, stats::CountingAllocator};
+use clap::{Args, Parser, Subcommand};
+
+use crate::{
+    answers::Answers,
+    config::Config,
+    days::{DAYS, Day, DayRun},
+    fetch::Fetched,
+    submit::{Outcome, Submitted},
+    table::{Table, format_bytes, format_duration},
+    verify::Status,
+};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2025 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day in sequence when no day is given
+    Run(RunArgs),
+    /// Download a day's puzzle input into the local cache
+    Fetch(FetchArgs),
+    /// Compute a day's answer and submit it to the website
+    Submit(SubmitArgs),
+    /// Rerun every day against its real input and compare with the known answers
+    Verify(VerifyArgs),
+    /// Write a random input in a day's format to stdout
+    Gen(GenArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+    day: Option<u8>,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Input file, or `-` for stdin (defaults to the cached input, then dayN/input.txt)
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Report wall time, allocations and peak heap for parse and each part
+    #[arg(long)]
+    stats: bool,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    #[arg(short, long, default_value_t = config::DEFAULT_YEAR)]
+    year: u16,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Website base URL, overriding AOC_BASE_URL and the config file
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    #[arg(short, long, default_value_t = config::DEFAULT_YEAR)]
+    year: u16,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Answer to send instead of running the solution
+    #[arg(short, long)]
+    answer: Option<String>,
+    /// Input file for the solution (defaults to the cached input, then dayN/input.txt)
+    #[arg(short, long, conflicts_with = "answer")]
+    input: Option<PathBuf>,
+    /// Website base URL, overriding AOC_BASE_URL and the config file
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// File of `day part answer` lines
+    #[arg(short, long, default_value = answers::DEFAULT_ANSWERS)]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+    day: u8,
+    /// The same seed always produces the same input
+    #[arg(short, long, default_value_t = 0)]
+    seed: u64,
+    /// Number of lines, ranges, grid rows or problems; very large sizes can
+    /// overflow some days' answers
+    #[arg(long, default_value_t = 1000)]
+    size: usize,
+    /// Row length for grids and battery banks, or numbers per day 6 problem
+    #[arg(short, long)]
+    width: Option<usize>,
+}
+
+impl RunArgs {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part.try_into().expect("part should be validated")],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match cli.command {
+        Command::Run(args) => run(&args, &config),
+        Command::Fetch(args) => fetch(&args, config),
+        Command::Submit(args) => submit(&args, config),
+        Command::Verify(args) => verify(&args, &config),
+        Command::Gen(args) => generate(&args),
+    }
+}
+
+fn run(args: &RunArgs, config: &Config) -> ExitCode {
+    match args.day {
+        Some(number) => run_day(
+            args,
+            config,
+            days::get_day(number).expect("day should be validated"),
+        ),
+        None => run_all(args, config),
+    }
+}
+
+fn run_day(args: &RunArgs, config: &Config, day: &Day) -> ExitCode {
+    let input = args
+        .input
+        .clone()
+        .unwrap_or_else(|| day.default_input(config));
+    let parts = args.parts();
+    match day.run(&input, &parts) {
+        Ok(run) => {
+            for part_run in &run.parts {
+                println!("Part {}: {}", part_run.part, part_run.answer);
+            }
+            if args.stats {
+                println!();
+                print_stats(&stats_rows(day.number, &run));
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err.diagnostic());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_all(args: &RunArgs, config: &Config) -> ExitCode {
+    let parts = args.parts();
+    let mut rows = Vec::new();
+    let mut stats = Vec::new();
+    let mut failed = false;
+    for day in DAYS.iter() {
+        let answers = match day.run(&day.default_input(config), &parts) {
+            Ok(run) => {
+                stats.extend(stats_rows(day.number, &run));
+                run.answers()
+            }
+            Err(err) => {
+                eprintln!("{}", err.diagnostic());
+                failed = true;
+                vec!["error".to_string(); parts.len()]
+            }
+        };
+        rows.push((day.number, answers));
+    }
+    print_summary(&parts, &rows);
+    if args.stats {
+        println!();
+        print_stats(&stats);
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn fetch(args: &FetchArgs, mut config: Config) -> ExitCode {
+    if let Some(base_url) = &args.base_url {
+        config.base_url = base_url.clone();
+    }
+    match fetch::fetch_input(&config, args.year, args.day) {
+        Ok(Fetched::Cached(path)) => {
+            println!("Using cached input {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Downloaded input to {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit(args: &SubmitArgs, mut config: Config) -> ExitCode {
+    if let Some(base_url) = &args.base_url {
+        config.base_url = base_url.clone();
+    }
+    let part = args.part.try_into().expect("part should be validated");
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let Some(day) = days::get_day(args.day) else {
+                eprintln!("error: no solution for day {}, pass --answer", args.day);
+                return ExitCode::FAILURE;
+            };
+            let input = args
+                .input
+                .clone()
+                .unwrap_or_else(|| day.default_input(&config));
+            match day.run(&input, &[part]) {
+                Ok(run) => run.answers().remove(0),
+                Err(err) => {
+                    eprintln!("{}", err.diagnostic());
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+
+    println!("Day {} part {part}: submitting {answer}", args.day);
+    match submit::submit_answer(&config, args.year, args.day, part, &answer) {
+        Ok(Submitted::Sent(outcome)) => {
+            println!("{outcome}");
+            if outcome == Outcome::Correct || outcome == Outcome::AlreadySolved {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Ok(Submitted::AlreadyCorrect(correct)) => {
+            println!("already solved with {correct}, not submitting");
+            ExitCode::SUCCESS
+        }
+        Ok(Submitted::AlreadyRejected(outcome)) => {
+            println!("already rejected as {outcome}, not submitting");
+            ExitCode::FAILURE
+        }
+        Ok(Submitted::LockedOut(seconds)) => {
+            println!("locked out for another {seconds}s, not submitting");
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn verify(args: &VerifyArgs, config: &Config) -> ExitCode {
+    let answers = match Answers::load(&args.answers) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let checks = verify::check_all(config, &answers);
+    verify::print_report(&checks);
+    // A day with an input but no known answer hasn't been checked, so it can't pass.
+    for check in checks
+        .iter()
+        .filter(|check| check.status == Status::Missing)
+    {
+        eprintln!(
+            "warning: no known answer for day {} part {}, add it to {}",
+            check.day,
+            check.part,
+            args.answers.display()
+        );
+    }
+    let failed = checks.iter().any(|check| {
+        matches!(
+            check.status,
+            Status::Fail(_) | Status::Missing | Status::Error(_)
+        )
+    });
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn generate(args: &GenArgs) -> ExitCode {
+    let day = days::get_day(args.day).expect("day should be validated");
+    let size = Size {
+        count: args.size,
+        width: args.width,
+    };
+    let mut out = BufWriter::new(io::stdout().lock());
+    match day
+        .generate(&mut out, args.seed, size)
+        .and_then(|()| out.flush())
+    {
+        Ok(()) => ExitCode::SUCCESS,
+        // Piping into `head` and friends closes stdout early, which is fine.
+        Err(err) if err.kind() == ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn print_summary(parts: &[Part], rows: &[(u8, Vec<String>)]) {
+    let headers = ["Day".to_string()]
+        .into_iter()
+        .chain(parts.iter().map(|part| format!("Part {part}")))
+        .collect();
+    let mut table = Table::new(headers);
+    for (number, answers) in rows {
+        table.push(
+            [number.to_string()]
+                .into_iter()
+                .chain(answers.clone())
+                .collect(),
+        );
+    }
+    table.print();
+}
+
+fn stats_rows(number: u8, run: &DayRun) -> Vec<Vec<String>> {
+    let phases = [("parse".to_string(), run.parse)].into_iter().chain(
+        run.parts
+            .iter()
+            .map(|part_run| (format!("part {}", part_run.part), part_run.usage)),
+    );
+    phases
+        .map(|(phase, usage)| {
+            vec![
+                number.to_string(),
+                phase,
+                format_duration(usage.time),
+                usage.allocations.to_string(),
+                format_bytes(usage.peak_bytes),
+            ]
+        })
+        .collect()
+}
+
+fn print_stats(rows: &[Vec<String>]) {
+    let headers = ["Day", "Phase", "Time", "Allocs", "Peak heap"];
+    let mut table = Table::new(headers.map(String::from).to_vec());
+    for row in rows {
+        table.push(row.clone());
+    }
+    table.print();
+}
@@ -0,0 +1,396 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::Part;
+
+use crate::{
+    client::{Client, ClientError},
+    config::Config,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    Wait(u64),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Outcome {
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        match self {
+            Outcome::Correct => Some("correct"),
+            Outcome::TooHigh => Some("too-high"),
+            Outcome::TooLow => Some("too-low"),
+            Outcome::Wrong => Some("wrong"),
+            _ => None,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Outcome> {
+        match name {
+            "correct" => Some(Outcome::Correct),
+            "too-high" => Some(Outcome::TooHigh),
+            "too-low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait(seconds) => write!(f, "submitted too recently, wait {seconds}s"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown(text) => write!(f, "unrecognised response: {text}"),
+        }
+    }
+}
+
+/// The outcome of a submission, plus how long the site asked us to wait before the next one.
+pub fn parse_response(html: &str) -> (Outcome, Option<u64>) {
+    let text = article_text(html);
+    let wait = parse_wait(&text);
+    let outcome = if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::Wait(wait.unwrap_or_default())
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else if text.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if text.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if text.contains("That's not the right answer") {
+        Outcome::Wrong
+    } else {
+        Outcome::Unknown(text)
+    };
+    (outcome, wait)
+}
+
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for char in article.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn parse_wait(text: &str) -> Option<u64> {
+    let text = text.to_lowercase();
+    if let Some(end) = text.find(" left to wait") {
+        let start = text[..end].rfind("you have ")? + "you have ".len();
+        return text[start..end]
+            .split_whitespace()
+            .try_fold(0u64, |total, part| {
+                let seconds = if let Some(hours) = part.strip_suffix('h') {
+                    hours.parse::<u64>().ok()?.checked_mul(3600)?
+                } else if let Some(minutes) = part.strip_suffix('m') {
+                    minutes.parse::<u64>().ok()?.checked_mul(60)?
+                } else {
+                    part.strip_suffix('s')?.parse().ok()?
+                };
+                total.checked_add(seconds)
+            });
+    }
+    let start = text.find("please wait ")? + "please wait ".len();
+    let mut words = text[start..].split_whitespace();
+    let count: u64 = match words.next()? {
+        "one" | "a" => 1,
+        "two" => 2,
+        "five" => 5,
+        "ten" => 10,
+        number => number.parse().ok()?,
+    };
+    match words.next()?.trim_end_matches(['.', ',']) {
+        "minute" | "minutes" => count.checked_mul(60),
+        "second" | "seconds" => Some(count),
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Client(ClientError),
+    Io(PathBuf, std::io::Error),
+    BadLog(PathBuf, usize),
+    EmptyAnswer,
+    /// Answers are logged as one word, and the site never expects spaces.
+    Whitespace(String),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Client(err) => write!(f, "{err}"),
+            SubmitError::Io(path, err) => write!(f, "failed to access {}: {err}", path.display()),
+            SubmitError::BadLog(path, line) => {
+                write!(f, "{}:{line}: invalid submission record", path.display())
+            }
+            SubmitError::EmptyAnswer => write!(f, "answer shouldn't be empty"),
+            SubmitError::Whitespace(answer) => {
+                write!(f, "answer '{answer}' shouldn't contain whitespace")
+            }
+        }
+    }
+}
+
+impl From<ClientError> for SubmitError {
+    fn from(value: ClientError) -> Self {
+        SubmitError::Client(value)
+    }
+}
+
+/// Every answer we have sent for a day, kept next to the cached input as
+/// `part answer outcome` lines plus an optional `locked-until <unix time>` line.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SubmissionLog {
+    entries: Vec<(Part, String, Outcome)>,
+    locked_until: Option<u64>,
+}
+
+impl SubmissionLog {
+    fn parse(path: &Path, text: &str) -> Result<Self, SubmitError> {
+        let mut log = SubmissionLog::default();
+        for (i, line) in text.lines().enumerate() {
+            let bad_log = || SubmitError::BadLog(path.to_path_buf(), i + 1);
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields[..] {
+                [] => {}
+                ["locked-until", time] => {
+                    log.locked_until = Some(time.parse().map_err(|_| bad_log())?)
+                }
+                [part, answer, outcome] => {
+                    let part = part
+                        .parse::<u8>()
+                        .ok()
+                        .and_then(|part| Part::try_from(part).ok())
+                        .ok_or_else(bad_log)?;
+                    let outcome = Outcome::from_name(outcome).ok_or_else(bad_log)?;
+                    log.entries.push((part, answer.to_string(), outcome));
+                }
+                _ => return Err(bad_log()),
+            }
+        }
+        Ok(log)
+    }
+
+    fn load(path: &Path) -> Result<Self, SubmitError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => SubmissionLog::parse(path, &text),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(SubmissionLog::default()),
+            Err(err) => Err(SubmitError::Io(path.to_path_buf(), err)),
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut text = String::new();
+        for (part, answer, outcome) in &self.entries {
+            let name = outcome.name().expect("only final outcomes are recorded");
+            text.push_str(&format!("{part} {answer} {name}\n"));
+        }
+        if let Some(time) = self.locked_until {
+            text.push_str(&format!("locked-until {time}\n"));
+        }
+        text
+    }
+
+    fn save(&self, path: &Path) -> Result<(), SubmitError> {
+        let text = self.render();
+        let to_error = |err| SubmitError::Io(path.to_path_buf(), err);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(to_error)?;
+        }
+        std::fs::write(path, text).map_err(to_error)
+    }
+
+    pub fn correct_answer(&self, part: Part) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(p, _, outcome)| *p == part && *outcome == Outcome::Correct)
+            .map(|(_, answer, _)| answer.as_str())
+    }
+
+    fn previous_outcome(&self, part: Part, answer: &str) -> Option<&Outcome> {
+        self.entries
+            .iter()
+            .find(|(p, a, _)| *p == part && a == answer)
+            .map(|(_, _, outcome)| outcome)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Submitted {
+    Sent(Outcome),
+    AlreadyCorrect(String),
+    AlreadyRejected(Outcome),
+    LockedOut(u64),
+}
+
+pub fn log_path(config: &Config, year: u16, day: u8) -> PathBuf {
+    config.day_dir(year, day).join("submissions.txt")
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("clock should be after the epoch")
+        .as_secs()
+}
+
+/// Sends an answer unless the log already knows how the site will respond.
+pub fn submit_answer(
+    config: &Config,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Submitted, SubmitError> {
+    if answer.is_empty() {
+        return Err(SubmitError::EmptyAnswer);
+    }
+    if answer.contains(char::is_whitespace) {
+        return Err(SubmitError::Whitespace(answer.to_string()));
+    }
+    let path = log_path(config, year, day);
+    let mut log = SubmissionLog::load(&path)?;
+    if let Some(correct) = log.correct_answer(part) {
+        return Ok(Submitted::AlreadyCorrect(correct.to_string()));
+    }
+    if let Some(outcome) = log.previous_outcome(part, answer) {
+        return Ok(Submitted::AlreadyRejected(outcome.clone()));
+    }
+    let now = now();
+    if let Some(locked_until) = log.locked_until
+        && locked_until > now
+    {
+        return Ok(Submitted::LockedOut(locked_until - now));
+    }
+
+    let html = Client::new(config)?.submit_answer(year, day, part.number(), answer)?;
+    let (outcome, wait) = parse_response(&html);
+    log.locked_until = wait.map(|seconds| now + seconds);
+    if outcome.is_wrong() || outcome == Outcome::Correct {
+        log.entries
+            .push((part, answer.to_string(), outcome.clone()));
+    }
+    log.save(&path)?;
+    Ok(Submitted::Sent(outcome))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_correct() {
+        let html = page(
+            "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+        );
+        assert_eq!(parse_response(&html), (Outcome::Correct, None));
+    }
+
+    #[test]
+    fn test_parse_too_high() {
+        let html = page(
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again.",
+        );
+        assert_eq!(parse_response(&html), (Outcome::TooHigh, Some(60)));
+    }
+
+    #[test]
+    fn test_parse_too_low() {
+        let html = page(
+            "That's not the right answer; your answer is too low. Because you have guessed incorrectly 5 times on this puzzle, please wait 5 minutes before trying again.",
+        );
+        assert_eq!(parse_response(&html), (Outcome::TooLow, Some(300)));
+    }
+
+    #[test]
+    fn test_parse_wrong() {
+        let html = page("That's not the right answer. Please wait one minute before trying again.");
+        assert_eq!(parse_response(&html), (Outcome::Wrong, Some(60)));
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        let html = page(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.",
+        );
+        assert_eq!(parse_response(&html), (Outcome::Wait(83), Some(83)));
+        assert_eq!(parse_wait("You have 2h 1m 5s left to wait."), Some(7265));
+        assert_eq!(parse_wait("You have 3é left to wait."), None);
+        assert_eq!(
+            parse_wait("You have 99999999999999999999h left to wait."),
+            None
+        );
+        assert_eq!(
+            parse_wait("You have 9999999999999999999m left to wait."),
+            None
+        );
+        assert_eq!(parse_wait("Please wait 9999999999999999999 minutes."), None);
+    }
+
+    #[test]
+    fn test_parse_already_solved() {
+        let html =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(parse_response(&html), (Outcome::AlreadySolved, None));
+    }
+
+    #[test]
+    fn test_log_round_trip() {
+        let path = Path::new("submissions.txt");
+        let text = "1 1000 too-high\n1 900 correct\n2 5 wrong\nlocked-until 1700000000\n";
+        let log = SubmissionLog::parse(path, text).unwrap();
+        assert_eq!(log.correct_answer(Part::One), Some("900"));
+        assert_eq!(log.correct_answer(Part::Two), None);
+        assert_eq!(log.previous_outcome(Part::Two, "5"), Some(&Outcome::Wrong));
+        assert_eq!(log.locked_until, Some(1700000000));
+        assert_eq!(log.render(), text);
+        assert_eq!(SubmissionLog::parse(path, &log.render()).unwrap(), log);
+    }
+
+    #[test]
+    fn test_rejects_whitespace_answer() {
+        let config = Config::default();
+        for answer in ["12 34", " 12"] {
+            assert!(matches!(
+                submit_answer(&config, 2025, 1, Part::One, answer),
+                Err(SubmitError::Whitespace(_))
+            ));
+        }
+        let err = submit_answer(&config, 2025, 1, Part::One, "").unwrap_err();
+        assert_eq!(err.to_string(), "answer shouldn't be empty");
+    }
+}
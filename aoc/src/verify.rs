@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use aoc_common::{Error, Part};
+
+use crate::{
+    answers::Answers,
+    config::Config,
+    days::DAYS,
+    table::{Table, format_duration},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail(String),
+    Missing,
+    NoInput,
+    Error(String),
+}
+
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<String>,
+    pub status: Status,
+    pub time: Option<Duration>,
+}
+
+/// Reruns every day against its real input and compares with the known answers.
+pub fn check_all(config: &Config, answers: &Answers) -> Vec<Check> {
+    let mut checks = Vec::new();
+    for day in DAYS.iter() {
+        let run = match day.run(&day.default_input(config), &Part::ALL) {
+            Ok(run) => run,
+            Err(err) => {
+                let status = match err {
+                    Error::Io { .. } => Status::NoInput,
+                    Error::Parse(_) => {
+                        eprintln!("{}", err.diagnostic());
+                        Status::Error(err.to_string())
+                    }
+                };
+                checks.extend(Part::ALL.map(|part| Check {
+                    day: day.number,
+                    part,
+                    answer: None,
+                    status: status.clone(),
+                    time: None,
+                }));
+                continue;
+            }
+        };
+        let parse_time = run.parse.time;
+        checks.extend(run.parts.into_iter().map(|part_run| {
+            let status = match answers.get(day.number, part_run.part) {
+                Some(expected) if expected == part_run.answer => Status::Pass,
+                Some(expected) => Status::Fail(expected.to_string()),
+                None => Status::Missing,
+            };
+            Check {
+                day: day.number,
+                part: part_run.part,
+                answer: Some(part_run.answer),
+                status,
+                time: Some(parse_time + part_run.usage.time),
+            }
+        }));
+    }
+    checks
+}
+
+pub fn print_report(checks: &[Check]) {
+    let headers = ["Day", "Part", "Result", "Answer", "Expected", "Time"];
+    let mut table = Table::new(headers.map(String::from).to_vec());
+    for check in checks {
+        let (result, expected) = match &check.status {
+            Status::Pass => ("pass", String::new()),
+            Status::Fail(expected) => ("FAIL", expected.clone()),
+            Status::Missing => ("missing", String::new()),
+            Status::NoInput => ("no input", String::new()),
+            Status::Error(_) => ("error", String::new()),
+        };
+        table.push(vec![
+            check.day.to_string(),
+            check.part.to_string(),
+            result.to_string(),
+            check.answer.clone().unwrap_or_default(),
+            expected,
+            check.time.map(format_duration).unwrap_or_default(),
+        ]);
+    }
+    table.print();
+
+    let count = |status: fn(&Status) -> bool| checks.iter().filter(|c| status(&c.status)).count();
+    println!(
+        "{} passed, {} failed, {} missing, {} without input, {} errors",
+        count(|s| *s == Status::Pass),
+        count(|s| matches!(s, Status::Fail(_))),
+        count(|s| *s == Status::Missing),
+        count(|s| *s == Status::NoInput),
+        count(|s| matches!(s, Status::Error(_))),
+    );
+}
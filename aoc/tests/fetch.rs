@@ -0,0 +1,62 @@
+mod common;
+
+use common::{MockServer, aoc, scratch_dir};
+
+#[test]
+fn test_fetch_downloads_then_uses_cache() {
+    let server = MockServer::start(|_| (200, "L68\nL30\n".to_string()));
+    let dir = scratch_dir("fetch");
+
+    let output = aoc(&dir, &server, &["fetch", "--year", "2025", "--day", "1"]);
+    assert!(output.status.success(), "{output:?}");
+    let cached = dir.join("inputs/2025/day01/input.txt");
+    assert_eq!(std::fs::read_to_string(&cached).unwrap(), "L68\nL30\n");
+
+    let output = aoc(&dir, &server, &["fetch", "--year", "2025", "--day", "1"]);
+    assert!(output.status.success(), "{output:?}");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2025/day/1/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+    assert!(
+        requests[0]
+            .header("user-agent")
+            .unwrap()
+            .contains("github.com/jmckinny/advent-of-code-2025")
+    );
+}
+
+#[test]
+fn test_fetch_reports_http_errors() {
+    let server = MockServer::start(|_| (404, "Not Found".to_string()));
+    let dir = scratch_dir("fetch-missing");
+
+    let output = aoc(&dir, &server, &["fetch", "--day", "25"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("HTTP 404"));
+    assert!(!dir.join("inputs/2025/day25/input.txt").exists());
+}
+
+#[test]
+fn test_fetch_reads_session_from_config_file() {
+    let server = MockServer::start(|_| (200, "3-5\n".to_string()));
+    let dir = scratch_dir("fetch-config");
+    std::fs::write(dir.join("config"), "session = from-file\n").unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["fetch", "--day", "5", "--base-url", &server.url])
+        .current_dir(&dir)
+        .env("AOC_CONFIG", dir.join("config"))
+        .env_remove("AOC_SESSION")
+        .env_remove("AOC_BASE_URL")
+        .env_remove("AOC_CACHE_DIR")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        server.requests()[0].header("cookie"),
+        Some("session=from-file")
+    );
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use std::{fs, process::Command};
+
+use common::scratch_dir;
+
+fn aoc(args: &[&str]) -> std::process::Output {
+    let dir = scratch_dir("gen");
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .current_dir(&dir)
+        .env("AOC_CONFIG", dir.join("config"))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    output
+}
+
+fn generate(day: &str, seed: &str) -> String {
+    let output = aoc(&["gen", "--day", day, "--seed", seed, "--size", "30"]);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_gen_is_deterministic() {
+    for day in 1..=7 {
+        let day = day.to_string();
+        assert_eq!(generate(&day, "11"), generate(&day, "11"));
+        assert_ne!(generate(&day, "11"), generate(&day, "12"));
+    }
+}
+
+#[test]
+fn test_gen_output_solves() {
+    let dir = scratch_dir("gen");
+    for day in 1..=7 {
+        let day = day.to_string();
+        let input = dir.join(format!("day{day}.txt"));
+        fs::write(&input, generate(&day, "2025")).unwrap();
+        let output = aoc(&["run", "--day", &day, "--input", input.to_str().unwrap()]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.starts_with("Part 1: "), "day {day}: {stdout}");
+    }
+}
+
+#[test]
+fn test_gen_width() {
+    let output = aoc(&["gen", "-d", "4", "--size", "3", "--width", "9"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 3);
+    assert!(stdout.lines().all(|line| line.len() == 9), "{stdout}");
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use common::scratch_dir;
+
+fn run_with_stdin(args: &[&str], stdin: &str) -> std::process::Output {
+    let dir = scratch_dir("run");
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .current_dir(&dir)
+        .env("AOC_CONFIG", dir.join("config"))
+        .env_remove("AOC_CACHE_DIR")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_run_from_stdin() {
+    let output = run_with_stdin(
+        &["run", "--day", "5", "--part", "2", "--input", "-"],
+        include_str!("../../day5/test_input.txt"),
+    );
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Part 2: 14\n");
+}
+
+#[test]
+fn test_run_stats() {
+    let output = run_with_stdin(
+        &["run", "-d", "3", "-i", "-", "--stats"],
+        include_str!("../../day3/test_input.txt"),
+    );
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Part 1: 357\nPart 2: 3121910778619\n"));
+    assert!(stdout.contains("Peak heap"));
+    for phase in ["parse", "part 1", "part 2"] {
+        assert!(stdout.lines().any(|line| line.contains(phase)), "{stdout}");
+    }
+}
+
+#[test]
+fn test_run_reports_parse_error() {
+    let output = run_with_stdin(&["run", "-d", "1", "-i", "-"], "L68\nR3o\n");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--> day 1, line 2, column 2"), "{stderr}");
+    assert!(stderr.contains("2 | R3o"), "{stderr}");
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use common::{MockServer, aoc, scratch_dir};
+
+fn page(message: &str) -> String {
+    format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+}
+
+#[test]
+fn test_submit_computes_and_records_correct_answer() {
+    let server = MockServer::start(|_| (200, page("That's the right answer!")));
+    let dir = scratch_dir("submit-correct");
+    std::fs::create_dir_all(dir.join("inputs/2025/day01")).unwrap();
+    std::fs::write(
+        dir.join("inputs/2025/day01/input.txt"),
+        include_str!("../../day1/test_input.txt"),
+    )
+    .unwrap();
+
+    let output = aoc(&dir, &server, &["submit", "--day", "1", "--part", "1"]);
+    assert!(output.status.success(), "{output:?}");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2025/day/1/answer");
+    assert_eq!(requests[0].body, "level=1&answer=3");
+
+    let log = std::fs::read_to_string(dir.join("inputs/2025/day01/submissions.txt")).unwrap();
+    assert_eq!(log, "1 3 correct\n");
+
+    let output = aoc(&dir, &server, &["submit", "-d", "1", "-p", "1", "-a", "4"]);
+    assert!(output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("already solved with 3"));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn test_submit_never_repeats_wrong_answer() {
+    let server = MockServer::start(|_| {
+        (
+            200,
+            page(
+                "That's not the right answer; your answer is too high. Please wait one minute before trying again.",
+            ),
+        )
+    });
+    let dir = scratch_dir("submit-wrong");
+
+    let output = aoc(
+        &dir,
+        &server,
+        &["submit", "-d", "2", "-p", "2", "-a", "999"],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("too high"));
+
+    let output = aoc(
+        &dir,
+        &server,
+        &["submit", "-d", "2", "-p", "2", "-a", "999"],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("already rejected as too high"));
+
+    let output = aoc(
+        &dir,
+        &server,
+        &["submit", "-d", "2", "-p", "2", "-a", "998"],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("locked out"));
+
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn test_submit_reports_wait() {
+    let server = MockServer::start(|_| {
+        (
+            200,
+            page(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.",
+            ),
+        )
+    });
+    let dir = scratch_dir("submit-wait");
+
+    let output = aoc(&dir, &server, &["submit", "-d", "3", "-p", "1", "-a", "1"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("wait 34s"));
+    let log = std::fs::read_to_string(dir.join("inputs/2025/day03/submissions.txt")).unwrap();
+    assert!(log.starts_with("locked-until "));
+}
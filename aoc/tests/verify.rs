@@ -0,0 +1,60 @@
+mod common;
+
+use common::{MockServer, aoc, scratch_dir};
+
+fn write_input(dir: &std::path::Path, day: u8, text: &str) {
+    let day_dir = dir.join(format!("inputs/2025/day{day:02}"));
+    std::fs::create_dir_all(&day_dir).unwrap();
+    std::fs::write(day_dir.join("input.txt"), text).unwrap();
+}
+
+#[test]
+fn test_verify_reports_pass_fail_and_missing() {
+    let server = MockServer::start(|_| (500, String::new()));
+    let dir = scratch_dir("verify");
+    write_input(&dir, 1, include_str!("../../day1/test_input.txt"));
+    write_input(&dir, 5, include_str!("../../day5/test_input.txt"));
+    std::fs::write(dir.join("answers.txt"), "1 1 3\n1 2 6\n5 1 3\n5 2 15\n").unwrap();
+
+    let output = aoc(&dir, &server, &["verify"]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("3 passed, 1 failed, 0 missing, 10 without input, 0 errors"),
+        "{stdout}"
+    );
+    assert!(
+        stdout
+            .lines()
+            .any(|line| line.contains("FAIL") && line.contains("15"))
+    );
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_verify_fails_with_missing_answers() {
+    let server = MockServer::start(|_| (500, String::new()));
+    let dir = scratch_dir("verify-missing");
+    write_input(&dir, 2, include_str!("../../day2/test_input.txt"));
+    std::fs::write(dir.join("answers.txt"), "2 1 1227775554\n").unwrap();
+
+    let output = aoc(&dir, &server, &["verify"]);
+    assert!(!output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("1 passed, 0 failed, 1 missing"), "{stdout}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("warning: no known answer for day 2 part 2"),
+        "{stderr}"
+    );
+
+    std::fs::write(
+        dir.join("answers.txt"),
+        "2 1 1227775554
+2 2 4174379265
+",
+    )
+    .unwrap();
+    let output = aoc(&dir, &server, &["verify"]);
+    assert!(output.status.success(), "{output:?}");
+}
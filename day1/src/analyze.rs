@@ -0,0 +1,329 @@
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_common::Part;
+
+use crate::{Dial, Direction, RotationCommand, Safe, ZeroCount};
+
+/// An edit to a single rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Change {
+    /// Turn the other way by the same amount.
+    Flip,
+    Remove,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Flip => write!(f, "flip"),
+            Change::Remove => write!(f, "remove"),
+        }
+    }
+}
+
+/// The zero counts the safe would have with one rotation changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Candidate {
+    /// Position of the changed rotation, from 0, counting each repeat on its own.
+    pub index: usize,
+    /// The rotation as written, before the change.
+    pub rotation: RotationCommand,
+    pub change: Change,
+    pub count: ZeroCount,
+}
+
+impl Candidate {
+    /// The answer to `part` after the change.
+    pub fn answer(&self, part: Part) -> u128 {
+        self.count.answer(part)
+    }
+}
+
+/// Most rotations [`analyze`] scores, counting each repeat on its own, as it
+/// keeps several values for every one.
+pub const MAX_ANALYZED: u128 = 1_000_000;
+
+/// The safe makes more rotations than [`MAX_ANALYZED`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct TooManyRotations(pub u128);
+
+impl Display for TooManyRotations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "can only analyze up to {MAX_ANALYZED} rotations, found {}",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for TooManyRotations {}
+
+#[derive(Debug)]
+pub struct Analysis {
+    /// The counts with no changes.
+    pub baseline: ZeroCount,
+    /// Every flip and removal, in input order.
+    pub candidates: Vec<Candidate>,
+}
+
+impl Analysis {
+    /// The `n` candidates giving the highest answer to `part`, or the lowest with
+    /// `minimize`, breaking ties by input order.
+    pub fn best(&self, part: Part, n: usize, minimize: bool) -> Vec<Candidate> {
+        let mut ranked = self.candidates.clone();
+        ranked.sort_by_key(|candidate| (candidate.index, candidate.change));
+        if minimize {
+            ranked.sort_by_key(|candidate| candidate.answer(part));
+        } else {
+            ranked.sort_by_key(|candidate| std::cmp::Reverse(candidate.answer(part)));
+        }
+        ranked.truncate(n);
+        ranked
+    }
+}
+
+/// Scores flipping or removing each rotation in O(n log n).
+///
+/// Changing rotation `i` moves every later position by the same offset, so the
+/// zeros the rest of the rotations would reach are exactly the points the
+/// original rotations reach at `-offset`. Those are counted by sweeping the
+/// rotations backwards, keeping how often each position is landed on and how
+/// many rotations' partial turns cover each queried point. Each repeat can be
+/// changed on its own, so repeats are written out one by one, up to
+/// [`MAX_ANALYZED`] rotations in all.
+pub fn analyze(safe: &Safe) -> Result<Analysis, TooManyRotations> {
+    let total: u128 = safe
+        .rotations
+        .iter()
+        .map(|&(_, times)| u128::from(times))
+        .sum();
+    if total > MAX_ANALYZED {
+        return Err(TooManyRotations(total));
+    }
+    let size = safe.dial.size();
+    let rotations: Vec<RotationCommand> = safe.each_rotation().collect();
+    let mut positions = Vec::with_capacity(rotations.len() + 1);
+    let mut prefix = Vec::with_capacity(rotations.len() + 1);
+    let mut dial = safe.dial;
+    let mut count = ZeroCount::default();
+    positions.push(dial.position());
+    prefix.push(count);
+    for rotation in &rotations {
+        count.record(&mut dial, rotation);
+        positions.push(dial.position());
+        prefix.push(count);
+    }
+
+    // Each change is scored as the prefix before it, the changed rotation itself,
+    // and the original suffix after it hitting `target` instead of zero.
+    struct Query {
+        own: ZeroCount,
+        target: u64,
+    }
+    let queries: Vec<[Query; 2]> = rotations
+        .iter()
+        .enumerate()
+        .map(|(i, rotation)| {
+            let mut flip_dial = Dial {
+                size,
+                position: positions[i],
+            };
+            let mut own = ZeroCount::default();
+            own.record(&mut flip_dial, &rotation.flipped());
+            let shift_to = |position: u64| offset(size, positions[i + 1], position);
+            [
+                Query {
+                    own,
+                    target: shift_to(flip_dial.position()),
+                },
+                Query {
+                    own: ZeroCount::default(),
+                    target: shift_to(positions[i]),
+                },
+            ]
+        })
+        .collect();
+
+    let mut points: Vec<u64> = queries.iter().flatten().map(|query| query.target).collect();
+    points.sort_unstable();
+    points.dedup();
+    let mut coverage = Coverage::new(points);
+    let mut landings: HashMap<u64, u128> = HashMap::new();
+    let mut full_turns: u128 = 0;
+    let mut candidates = Vec::with_capacity(queries.len() * 2);
+    for (i, rotation) in rotations.iter().enumerate().rev() {
+        // Pushed backwards, like the sweep, so one reverse restores input order.
+        for (query, change) in queries[i].iter().zip([Change::Flip, Change::Remove]).rev() {
+            let landed = landings.get(&query.target).copied().unwrap_or_default();
+            let passed = full_turns + coverage.get(query.target);
+            candidates.push(Candidate {
+                index: i,
+                rotation: *rotation,
+                change,
+                count: ZeroCount {
+                    landed: prefix[i].landed + query.own.landed + landed,
+                    passed: prefix[i].passed + query.own.passed + passed,
+                },
+            });
+        }
+        *landings.entry(positions[i + 1]).or_default() += 1;
+        full_turns += u128::from(rotation.amount / size);
+        coverage.add_turn(size, positions[i], rotation);
+    }
+    candidates.reverse();
+    Ok(Analysis {
+        baseline: count,
+        candidates,
+    })
+}
+
+/// The point the original dial is at when a changed dial would be at zero, given
+/// the original is at `original` while the changed one is at `changed`.
+fn offset(size: u64, original: u64, changed: u64) -> u64 {
+    ((u128::from(original) + u128::from(size) - u128::from(changed)) % u128::from(size)) as u64
+}
+
+/// How many rotations' partial turns cover each of a fixed set of points, with
+/// the points compressed into a Fenwick tree of differences.
+struct Coverage {
+    points: Vec<u64>,
+    tree: Vec<i64>,
+}
+
+impl Coverage {
+    fn new(points: Vec<u64>) -> Self {
+        let tree = vec![0; points.len() + 1];
+        Coverage { points, tree }
+    }
+
+    /// Counts the positions `rotation` passes without completing a full turn,
+    /// excluding where it starts and including where it stops.
+    fn add_turn(&mut self, size: u64, start: u64, rotation: &RotationCommand) {
+        let clicks = rotation.amount % size;
+        if clicks == 0 {
+            return;
+        }
+        let (size, start, clicks) = (u128::from(size), u128::from(start), u128::from(clicks));
+        let (low, high) = match rotation.direction {
+            Direction::Right => ((start + 1) % size, (start + clicks) % size),
+            Direction::Left => ((start + size - clicks) % size, (start + size - 1) % size),
+        };
+        let (low, high) = (low as u64, high as u64);
+        if low <= high {
+            self.add_range(low, high);
+        } else {
+            self.add_range(low, (size - 1) as u64);
+            self.add_range(0, high);
+        }
+    }
+
+    fn add_range(&mut self, low: u64, high: u64) {
+        let first = self.points.partition_point(|&point| point < low);
+        let end = self.points.partition_point(|&point| point <= high);
+        if first < end {
+            self.update(first, 1);
+            self.update(end, -1);
+        }
+    }
+
+    fn update(&mut self, index: usize, delta: i64) {
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    fn get(&self, point: u64) -> u128 {
+        let index = self
+            .points
+            .binary_search(&point)
+            .expect("point should have been registered");
+        let mut i = index + 1;
+        let mut total = 0;
+        while i > 0 {
+            total += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        total as u128
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day1;
+
+    /// Re-simulates the whole list for every candidate.
+    fn brute_force(safe: &Safe) -> Vec<Candidate> {
+        let rotations: Vec<RotationCommand> = safe.each_rotation().collect();
+        let mut candidates = Vec::new();
+        for (index, &original) in rotations.iter().enumerate() {
+            for change in [Change::Flip, Change::Remove] {
+                let mut dial = safe.dial;
+                let mut count = ZeroCount::default();
+                for (i, rotation) in rotations.iter().enumerate() {
+                    if i != index {
+                        count.record(&mut dial, rotation);
+                    } else if change == Change::Flip {
+                        count.record(&mut dial, &rotation.flipped());
+                    }
+                }
+                candidates.push(Candidate {
+                    index,
+                    rotation: original,
+                    change,
+                    count,
+                });
+            }
+        }
+        candidates
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        for input in [
+            aoc_common::example_file!("test_input.txt"),
+            aoc_common::example_file!("test_edgecase.txt"),
+            aoc_common::example_file!("test_edgecase2.txt"),
+            "dial size=7 start=3\nL3\nR10\nL14\nR7\nL1\n",
+            "dial size=1 start=0\nL3\nR2\n",
+            "dial size=9 start=4\nR3x5\nL12x2\nR9\n",
+        ] {
+            let safe = Day1::parse(input).unwrap();
+            let analysis = analyze(&safe).unwrap();
+            assert_eq!(analysis.baseline, safe.count_zeros());
+            assert_eq!(analysis.candidates, brute_force(&safe), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_best() {
+        let safe = Day1::parse(aoc_common::example_file!("test_input.txt")).unwrap();
+        let analysis = analyze(&safe).unwrap();
+        let best = analysis.best(Part::One, 3, false);
+        assert_eq!(best.len(), 3);
+        assert!(
+            best.windows(2)
+                .all(|pair| pair[0].answer(Part::One) >= pair[1].answer(Part::One))
+        );
+        let worst = analysis.best(Part::Two, 1, true)[0];
+        let lowest = analysis.candidates.iter().map(|c| c.count.passed).min();
+        assert_eq!(Some(worst.count.passed), lowest);
+    }
+
+    #[test]
+    fn test_too_many_rotations() {
+        let safe = Day1::parse("R1x100000000000000\n").unwrap();
+        assert_eq!(
+            analyze(&safe).unwrap_err(),
+            TooManyRotations(100_000_000_000_000)
+        );
+
+        let safe = Day1::parse("L2x500000\nR3x500001\n").unwrap();
+        assert_eq!(analyze(&safe).unwrap_err(), TooManyRotations(1_000_001));
+    }
+}
@@ -0,0 +1,231 @@
+use std::f64::consts::TAU;
+
+use crate::{Dial, RotationCommand, Safe, ZeroCount};
+
+/// Most ticks drawn on the ring; larger dials share each tick between positions.
+pub const MAX_TICKS: u64 = 100;
+
+const RADIUS_ROWS: usize = 10;
+// Terminal cells are about twice as tall as they are wide.
+const RADIUS_COLS: usize = 2 * RADIUS_ROWS + 2;
+
+const RESET: &str = "\x1b[0m";
+const POINTER: &str = "\x1b[1;33m";
+const ZERO: &str = "\x1b[1;32m";
+const FLASH: &str = "\x1b[1;97;41m";
+
+/// The dial part way through the rotations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    /// Rotation being animated, from 0, counting each repeat on its own.
+    pub index: usize,
+    pub rotation: RotationCommand,
+    pub position: u64,
+    /// Totals so far, including this frame.
+    pub count: ZeroCount,
+    /// Clicks in this frame that left the dial on zero.
+    pub hits: u64,
+    /// Whether this frame finished a rotation on zero.
+    pub landed: bool,
+}
+
+/// Frames for every rotation, splitting each into at most `frames_per_rotation`
+/// steps so long rotations still animate quickly.
+pub struct Frames<'a> {
+    safe: &'a Safe,
+    dial: Dial,
+    count: ZeroCount,
+    index: usize,
+    /// Entry of `safe.rotations` being animated, and how many of its repeats
+    /// are done.
+    entry: usize,
+    repeats_done: u64,
+    /// Clicks left in the current rotation, once it has started.
+    remaining: Option<u64>,
+    frames_per_rotation: u64,
+}
+
+pub fn frames(safe: &Safe, frames_per_rotation: u64) -> Frames<'_> {
+    Frames {
+        safe,
+        dial: safe.dial,
+        count: ZeroCount::default(),
+        index: 0,
+        entry: 0,
+        repeats_done: 0,
+        remaining: None,
+        frames_per_rotation: frames_per_rotation.max(1),
+    }
+}
+
+impl Iterator for Frames<'_> {
+    type Item = Frame;
+
+    fn next(&mut self) -> Option<Frame> {
+        let &(rotation, times) = self.safe.rotations.get(self.entry)?;
+        let remaining = *self.remaining.get_or_insert(rotation.amount);
+        let clicks = rotation
+            .amount
+            .div_ceil(self.frames_per_rotation)
+            .max(1)
+            .min(remaining);
+        let hits = self.dial.rotate(&RotationCommand {
+            direction: rotation.direction,
+            amount: clicks,
+        });
+        self.count.passed += u128::from(hits);
+        let finished = remaining == clicks;
+        let landed = finished && self.dial.position() == 0;
+        if landed {
+            self.count.landed += 1;
+        }
+        let frame = Frame {
+            index: self.index,
+            rotation,
+            position: self.dial.position(),
+            count: self.count,
+            hits,
+            landed,
+        };
+        if finished {
+            self.index += 1;
+            self.repeats_done += 1;
+            if self.repeats_done == times {
+                self.entry += 1;
+                self.repeats_done = 0;
+            }
+            self.remaining = None;
+        } else {
+            self.remaining = Some(remaining - clicks);
+        }
+        Some(frame)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Plain,
+    Pointer,
+    Zero,
+    Flash,
+}
+
+/// Draws the dial as a ring of ticks with zero at the top, the pointer
+/// highlighted and the ring flashing on frames that reach zero. Lines contain
+/// ANSI colour codes.
+pub fn render(safe: &Safe, frame: &Frame) -> Vec<String> {
+    let size = safe.dial.size();
+    let ticks = size.min(MAX_TICKS);
+    let flash = frame.hits > 0;
+    let mut grid = vec![vec![(' ', Style::Plain); 2 * RADIUS_COLS + 1]; 2 * RADIUS_ROWS + 1];
+    let ring = if flash { Style::Flash } else { Style::Plain };
+    for tick in 0..ticks {
+        let (row, col) = tick_cell(tick, ticks);
+        grid[row][col] = match tick {
+            0 if flash => ('*', Style::Flash),
+            0 => ('0', Style::Zero),
+            _ => ('.', ring),
+        };
+    }
+    let pointer = (u128::from(frame.position) * u128::from(ticks) / u128::from(size)) as u64;
+    let (row, col) = tick_cell(pointer, ticks);
+    grid[row][col] = ('@', Style::Pointer);
+
+    let label = [
+        format!("#{} {}", frame.index, frame.rotation),
+        format!("at {} of {size}", frame.position),
+        format!("landed {}", frame.count.landed),
+        format!("passed {}", frame.count.passed),
+    ];
+    for (i, text) in label.iter().enumerate() {
+        let row = RADIUS_ROWS - label.len() / 2 + i;
+        let start = RADIUS_COLS.saturating_sub(text.chars().count() / 2);
+        for (col, char) in text.chars().enumerate() {
+            if let Some(cell) = grid[row].get_mut(start + col) {
+                *cell = (char, Style::Plain);
+            }
+        }
+    }
+
+    grid.iter()
+        .map(|row| {
+            let mut line = String::new();
+            for &(char, style) in row {
+                match style {
+                    Style::Plain => line.push(char),
+                    Style::Pointer => line.push_str(&format!("{POINTER}{char}{RESET}")),
+                    Style::Zero => line.push_str(&format!("{ZERO}{char}{RESET}")),
+                    Style::Flash => line.push_str(&format!("{FLASH}{char}{RESET}")),
+                }
+            }
+            line.trim_end().to_string()
+        })
+        .collect()
+}
+
+/// Grid cell of a tick, going clockwise from the top.
+fn tick_cell(tick: u64, ticks: u64) -> (usize, usize) {
+    let angle = TAU * tick as f64 / ticks as f64;
+    let row = RADIUS_ROWS as f64 * (1.0 - angle.cos());
+    let col = RADIUS_COLS as f64 * (1.0 + angle.sin());
+    (row.round() as usize, col.round() as usize)
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day1;
+
+    fn strip_ansi(line: &str) -> String {
+        let mut plain = String::new();
+        let mut chars = line.chars();
+        while let Some(char) = chars.next() {
+            if char == '\x1b' {
+                chars.by_ref().find(|&c| c == 'm');
+            } else {
+                plain.push(char);
+            }
+        }
+        plain
+    }
+
+    #[test]
+    fn test_frames_total_matches_count() {
+        for input in [
+            aoc_common::example_file!("test_input.txt"),
+            aoc_common::example_file!("test_edgecase.txt"),
+            "dial size=1 start=0\nL3\nR0\nR2\n",
+            "dial size=10 start=0\nR5x4\nL30x2\n",
+        ] {
+            let safe = Day1::parse(input).unwrap();
+            for frames_per_rotation in [1, 7, 1000] {
+                let last = frames(&safe, frames_per_rotation).last().unwrap();
+                assert_eq!(last.count, safe.count_zeros(), "{input:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_long_rotations_are_split() {
+        let safe = Day1::parse("R1000\nL3\n").unwrap();
+        let frames: Vec<Frame> = frames(&safe, 10).collect();
+        assert_eq!(frames.iter().filter(|frame| frame.index == 0).count(), 10);
+        assert_eq!(frames.iter().filter(|frame| frame.index == 1).count(), 3);
+        assert_eq!(frames[0].position, 50);
+    }
+
+    #[test]
+    fn test_render_ring() {
+        let safe = Day1::parse("L50\n").unwrap();
+        let frame = frames(&safe, 1).next().unwrap();
+        let rendered = render(&safe, &frame);
+        let lines: Vec<String> = rendered.iter().map(|line| strip_ansi(line)).collect();
+        assert_eq!(lines.len(), 2 * RADIUS_ROWS + 1);
+        // The pointer covers zero at the top, and the ring flashes.
+        assert_eq!(lines[0].chars().nth(RADIUS_COLS), Some('@'));
+        assert!(rendered.iter().any(|line| line.contains(FLASH)));
+        assert!(lines.iter().any(|line| line.contains("landed 1")));
+    }
+}
@@ -0,0 +1,716 @@
+mod analyze;
+mod animate;
+mod milestones;
+mod stream;
+mod targets;
+mod trace;
+
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use aoc_common::{
+    LineError, ParseError, Part, Solution,
+    generate::{Rng, Size},
+};
+
+pub use analyze::{Analysis, Candidate, Change, MAX_ANALYZED, TooManyRotations, analyze};
+pub use animate::{Frame, Frames, MAX_TICKS, frames, render};
+pub use milestones::{Milestone, Milestones};
+pub use stream::{RotationReader, StreamError};
+pub use targets::{TargetCount, TargetCounts};
+pub use trace::{Step, trace, write_csv, write_json};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn flipped(self) -> Direction {
+        match self {
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Left => write!(f, "L"),
+            Direction::Right => write!(f, "R"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DirectionParseError {
+    BadInput(String),
+    NoInput,
+    ParseIntError(ParseIntError),
+    BadRepeat(ParseIntError),
+    ZeroRepeat,
+    TooManyRepeats(u64),
+    /// A repeat suffix where only one rotation was expected.
+    Repeated(u64),
+}
+
+impl Display for DirectionParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DirectionParseError::BadInput(s) => {
+                write!(f, "invalid direction '{s}', expected 'L' or 'R'")
+            }
+            DirectionParseError::NoInput => {
+                write!(f, "expected a rotation command, found an empty line")
+            }
+            DirectionParseError::ParseIntError(err) => write!(f, "invalid rotation amount: {err}"),
+            DirectionParseError::BadRepeat(err) => write!(f, "invalid repeat count: {err}"),
+            DirectionParseError::ZeroRepeat => write!(f, "repeat count should be at least 1"),
+            DirectionParseError::TooManyRepeats(repeat) => write!(
+                f,
+                "repeat count {repeat} would make more than {} clicks",
+                u64::MAX
+            ),
+            DirectionParseError::Repeated(repeat) => {
+                write!(
+                    f,
+                    "expected a single rotation, found one repeated {repeat} times"
+                )
+            }
+        }
+    }
+}
+
+impl LineError for DirectionParseError {
+    fn span<'a>(&self, line: &'a str) -> &'a str {
+        let Some(fields) = Fields::split(line) else {
+            return line;
+        };
+        match self {
+            DirectionParseError::BadInput(_) => fields.direction,
+            DirectionParseError::NoInput => line,
+            DirectionParseError::ParseIntError(_) => fields.amount,
+            DirectionParseError::BadRepeat(_)
+            | DirectionParseError::ZeroRepeat
+            | DirectionParseError::TooManyRepeats(_)
+            | DirectionParseError::Repeated(_) => fields.repeat.unwrap_or(line),
+        }
+    }
+}
+
+impl From<ParseIntError> for DirectionParseError {
+    fn from(value: ParseIntError) -> Self {
+        DirectionParseError::ParseIntError(value)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = DirectionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" | "l" => Ok(Direction::Left),
+            "R" | "r" => Ok(Direction::Right),
+            _ => Err(DirectionParseError::BadInput(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RotationCommand {
+    direction: Direction,
+    amount: u64,
+}
+
+impl RotationCommand {
+    /// The same amount in the other direction.
+    pub fn flipped(&self) -> RotationCommand {
+        RotationCommand {
+            direction: self.direction.flipped(),
+            amount: self.amount,
+        }
+    }
+}
+
+impl Display for RotationCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.direction, self.amount)
+    }
+}
+
+impl FromStr for RotationCommand {
+    type Err = DirectionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse()? {
+            RotationLine { rotation: None, .. } => Err(DirectionParseError::NoInput),
+            RotationLine {
+                rotation: Some(rotation),
+                repeat: 1,
+            } => Ok(rotation),
+            RotationLine { repeat, .. } => Err(DirectionParseError::Repeated(repeat)),
+        }
+    }
+}
+
+/// One line of rotations, like `R25` or `l3`, with surrounding whitespace and a
+/// trailing `# comment` allowed. A suffix such as `R25x4` makes the same rotation
+/// four times in a row, and blank or comment-only lines hold no rotation at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RotationLine {
+    pub rotation: Option<RotationCommand>,
+    /// Times in a row `rotation` is made, at least 1. A line makes no more
+    /// clicks than one rotation could, so the totals can't overflow.
+    pub repeat: u64,
+}
+
+impl FromStr for RotationLine {
+    type Err = DirectionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(fields) = Fields::split(s) else {
+            return Ok(RotationLine {
+                rotation: None,
+                repeat: 1,
+            });
+        };
+        let direction = fields.direction.parse()?;
+        let amount: u64 = fields.amount.parse()?;
+        let repeat = match fields.repeat {
+            Some(repeat) => repeat.parse().map_err(DirectionParseError::BadRepeat)?,
+            None => 1,
+        };
+        if repeat == 0 {
+            return Err(DirectionParseError::ZeroRepeat);
+        }
+        if amount.max(1).checked_mul(repeat).is_none() {
+            return Err(DirectionParseError::TooManyRepeats(repeat));
+        }
+        Ok(RotationLine {
+            rotation: Some(RotationCommand { direction, amount }),
+            repeat,
+        })
+    }
+}
+
+/// The parts of a rotation line, kept as slices of it so errors can point at them.
+struct Fields<'a> {
+    direction: &'a str,
+    amount: &'a str,
+    repeat: Option<&'a str>,
+}
+
+impl<'a> Fields<'a> {
+    /// Splits `line` after dropping its comment and surrounding whitespace, or
+    /// returns `None` if nothing is left.
+    fn split(line: &'a str) -> Option<Self> {
+        let code = line.split('#').next().unwrap_or_default().trim();
+        let direction_len = code.chars().next()?.len_utf8();
+        let (direction, rest) = code.split_at(direction_len);
+        let (amount, repeat) = match rest.split_once(['x', 'X']) {
+            Some((amount, repeat)) => (amount, Some(repeat)),
+            None => (rest, None),
+        };
+        Some(Fields {
+            direction,
+            amount,
+            repeat,
+        })
+    }
+}
+
+/// A safe's dial, numbered `0..size`, that remembers where it points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    size: u64,
+    position: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DialError {
+    NoPositions,
+    StartOffDial { size: u64, start: u64 },
+    TargetOffDial { size: u64, target: u64 },
+}
+
+impl Display for DialError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DialError::NoPositions => write!(f, "dial size should be at least 1"),
+            DialError::StartOffDial { size, start } => {
+                write!(f, "dial start should be in 0..{size}, found {start}")
+            }
+            DialError::TargetOffDial { size, target } => {
+                write!(f, "target should be in 0..{size}, found {target}")
+            }
+        }
+    }
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial {
+            size: 100,
+            position: 50,
+        }
+    }
+}
+
+impl Dial {
+    pub fn new(size: u64, start: u64) -> Result<Self, DialError> {
+        if size == 0 {
+            return Err(DialError::NoPositions);
+        }
+        if start >= size {
+            return Err(DialError::StartOffDial { size, start });
+        }
+        Ok(Dial {
+            size,
+            position: start,
+        })
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Turns the dial, returning how many clicks left it pointing at zero.
+    pub fn rotate(&mut self, rotation: &RotationCommand) -> u64 {
+        let zeros = self.hits(rotation, 0);
+        self.turn(rotation);
+        zeros
+    }
+
+    /// How many clicks of `rotation` would leave the dial pointing at `target`,
+    /// without turning it.
+    pub fn hits(&self, rotation: &RotationCommand, target: u64) -> u64 {
+        // A rotation can't reach the target more often than it clicks, so this fits.
+        self.clicks_to(rotation.direction, u128::from(rotation.amount), target) as u64
+    }
+
+    /// How many of `times` rotations in a row would stop on `target`, and how
+    /// many of their clicks would leave the dial pointing at it, without turning
+    /// it or making the rotations one by one.
+    pub fn repeated_hits(
+        &self,
+        rotation: &RotationCommand,
+        times: u64,
+        target: u64,
+    ) -> (u64, u128) {
+        // Clicks don't care where one rotation ends and the next begins.
+        let amount = u128::from(rotation.amount) * u128::from(times);
+        let passed = self.clicks_to(rotation.direction, amount, target);
+
+        // Rotation `i` stops `i * step` clicks on, so it stops on the target when
+        // `i * step` is the distance to it, modulo the size.
+        let size = u128::from(self.size);
+        let position = self.distance_past(target);
+        let distance = match rotation.direction {
+            Direction::Right => (size - position) % size,
+            Direction::Left => position,
+        };
+        let step = u128::from(rotation.amount) % size;
+        let divisor = gcd(step, size);
+        if distance % divisor != 0 {
+            return (0, passed);
+        }
+        // Stops repeat every `period` rotations, starting from `first`.
+        let period = size / divisor;
+        let first = match distance / divisor * inverse(step / divisor, period) % period {
+            0 => period,
+            first => first,
+        };
+        let times = u128::from(times);
+        let landed = if first > times {
+            0
+        } else {
+            (times - first) / period + 1
+        };
+        // At most `times`, so this fits.
+        (landed as u64, passed)
+    }
+
+    /// Clicks of a rotation by `amount` that would leave the dial pointing at
+    /// `target`.
+    fn clicks_to(&self, direction: Direction, amount: u128, target: u64) -> u128 {
+        // Widened so `position + amount` can't overflow, even on dials near `u64::MAX`.
+        let size = u128::from(self.size);
+        let position = self.distance_past(target);
+        match direction {
+            Direction::Right => (position + amount) / size,
+            Direction::Left if position == 0 => amount / size,
+            Direction::Left if amount >= position => (amount - position) / size + 1,
+            Direction::Left => 0,
+        }
+    }
+
+    /// The position measured from `target`, so hitting the target is reaching zero.
+    fn distance_past(&self, target: u64) -> u128 {
+        let size = u128::from(self.size);
+        (u128::from(self.position) + size - u128::from(target) % size) % size
+    }
+
+    fn turn(&mut self, rotation: &RotationCommand) {
+        self.turn_repeated(rotation, 1);
+    }
+
+    fn turn_repeated(&mut self, rotation: &RotationCommand, times: u64) {
+        let size = u128::from(self.size);
+        let position = u128::from(self.position);
+        let amount = u128::from(rotation.amount) % size * (u128::from(times) % size) % size;
+        let position = match rotation.direction {
+            Direction::Right => (position + amount) % size,
+            Direction::Left => (position + size - amount) % size,
+        };
+        self.position = position as u64;
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// The inverse of `n` modulo `modulus`, which must be coprime with it.
+fn inverse(n: u128, modulus: u128) -> u128 {
+    // Extended Euclid, where every value fits an `i128` as the modulus fits a `u64`.
+    let (mut old_r, mut r) = (n as i128, modulus as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+    old_s.rem_euclid(modulus as i128) as u128
+}
+
+/// Running totals for both parts, so rotations can be counted as they stream past.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ZeroCount {
+    /// Rotations that stopped on zero, wider than a line's repeat count for the
+    /// same reason as `passed`.
+    pub landed: u128,
+    /// Clicks that left the dial on zero; wider than a rotation's amount because
+    /// every rotation can contribute up to `u64::MAX`.
+    pub passed: u128,
+}
+
+impl ZeroCount {
+    /// The answer to `part` with these totals.
+    pub fn answer(&self, part: Part) -> u128 {
+        match part {
+            Part::One => self.landed,
+            Part::Two => self.passed,
+        }
+    }
+
+    pub fn record(&mut self, dial: &mut Dial, rotation: &RotationCommand) {
+        self.passed += u128::from(dial.rotate(rotation));
+        if dial.position() == 0 {
+            self.landed += 1;
+        }
+    }
+
+    /// Records `rotation` made `times` times in a row, in one step however many
+    /// times that is.
+    pub fn record_repeated(&mut self, dial: &mut Dial, rotation: &RotationCommand, times: u64) {
+        let (landed, passed) = dial.repeated_hits(rotation, times, 0);
+        self.landed += u128::from(landed);
+        self.passed += passed;
+        dial.turn_repeated(rotation, times);
+    }
+}
+
+/// The dial and the rotations to apply to it.
+#[derive(Debug)]
+pub struct Safe {
+    pub dial: Dial,
+    /// Each rotation with the times in a row it is made, so repeats stay as
+    /// written rather than being expanded.
+    pub rotations: Vec<(RotationCommand, u64)>,
+}
+
+impl Safe {
+    pub fn count_zeros(&self) -> ZeroCount {
+        let mut dial = self.dial;
+        let mut count = ZeroCount::default();
+        for (rotation, times) in &self.rotations {
+            count.record_repeated(&mut dial, rotation, *times);
+        }
+        count
+    }
+
+    /// Every rotation made, writing out repeats one by one.
+    pub fn each_rotation(&self) -> impl Iterator<Item = RotationCommand> + '_ {
+        self.rotations
+            .iter()
+            .flat_map(|&(rotation, times)| std::iter::repeat_n(rotation, times as usize))
+    }
+}
+
+const HEADER: &str = "dial";
+
+/// Whether `line` holds nothing but whitespace and a comment, so it may come
+/// before the header.
+fn is_blank(line: &str) -> bool {
+    Fields::split(line).is_none()
+}
+
+/// Parses a `dial size=100 start=50` header, the first line that isn't blank;
+/// either setting may be left out, and a `# comment` may follow.
+fn parse_header(line_number: usize, line: &str, settings: &str) -> Result<Dial, ParseError> {
+    let default = Dial::default();
+    let (mut size, mut start) = (default.size, default.position);
+    let settings = settings.split('#').next().unwrap_or_default();
+    for setting in settings.split_whitespace() {
+        let error = |message| ParseError::on_line(Day1::DAY, line_number, line, setting, message);
+        let (key, value) = setting.split_once('=').ok_or_else(|| {
+            error(format!(
+                "expected a setting like 'size=100', found '{setting}'"
+            ))
+        })?;
+        let value = value
+            .parse()
+            .map_err(|err| error(format!("invalid {key} '{value}': {err}")))?;
+        match key {
+            "size" => size = value,
+            "start" => start = value,
+            _ => {
+                return Err(error(format!(
+                    "unknown dial setting '{key}', expected 'size' or 'start'"
+                )));
+            }
+        }
+    }
+    Dial::new(size, start)
+        .map_err(|err| ParseError::on_line(Day1::DAY, line_number, line, line, err.to_string()))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Safe;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    /// Rotations, one [`RotationLine`] per line, optionally preceded by a `dial`
+    /// header line.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = aoc_common::lines(input).enumerate().peekable();
+        while lines.next_if(|(_, line)| is_blank(line)).is_some() {}
+        let dial = match lines.peek().and_then(|(_, line)| line.strip_prefix(HEADER)) {
+            Some(settings) => {
+                let (index, line) = lines.next().expect("header line should exist");
+                parse_header(index + 1, line, settings)?
+            }
+            None => Dial::default(),
+        };
+        let mut rotations = Vec::new();
+        for (_, line) in lines {
+            let line: RotationLine = aoc_common::parse_line(Self::DAY, input, line)?;
+            rotations.extend(line.rotation.map(|rotation| (rotation, line.repeat)));
+        }
+        Ok(Safe { dial, rotations })
+    }
+
+    fn part1(safe: &Self::Input) -> u128 {
+        safe.count_zeros().landed
+    }
+
+    fn part2(safe: &Self::Input) -> u128 {
+        safe.count_zeros().passed
+    }
+}
+
+/// Writes `size.count` rotations of up to 999 clicks in either direction.
+pub fn generate(out: &mut dyn Write, rng: &mut Rng, size: Size) -> io::Result<()> {
+    for _ in 0..size.count {
+        let direction = rng.choose(&['L', 'R']);
+        writeln!(out, "{direction}{}", rng.range(1..=999))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+const LARGE_DIAL: &str = "dial size=2000000000 start=1999999999\nR2000000000\nR1\nL2000000000\n";
+
+#[cfg(test)]
+const SCENARIO: &str = "\
+l50   # straight to zero
+\t
+# four quarter turns, landing on zero again
+R25x4
+  r100x2
+";
+
+#[cfg(test)]
+const HUGE_AMOUNTS: &str = "dial size=1 start=0\nR18446744073709551615\nL18446744073709551615\n";
+
+aoc_common::examples! {
+    Day1 {
+        example_part1: example_file!("test_input.txt"), part1 => 3,
+        example_part2: example_file!("test_input.txt"), part2 => 6,
+        edgecase_part2: example_file!("test_edgecase.txt"), part2 => 20,
+        edgecase2_part2: example_file!("test_edgecase2.txt"), part2 => 2,
+        full_turns_part1: "L50\nR100\nL300\n", part1 => 3,
+        full_turns_part2: "L50\nR100\nL300\n", part2 => 5,
+        single_position_part1: "dial size=1 start=0\nL5\nR3\n", part1 => 2,
+        single_position_part2: "dial size=1 start=0\nL5\nR3\n", part2 => 8,
+        large_dial_part1: LARGE_DIAL, part1 => 2,
+        large_dial_part2: LARGE_DIAL, part2 => 3,
+        header_defaults_part2: "dial\nL68\nL30\nR48\n", part2 => 2,
+        huge_amounts_part1: HUGE_AMOUNTS, part1 => 2,
+        huge_amounts_part2: HUGE_AMOUNTS, part2 => 2 * u64::MAX as u128,
+        scenario_part1: SCENARIO, part1 => 4,
+        scenario_part2: SCENARIO, part2 => 4,
+        long_repeat_part1: "R1x100000000000000\n", part1 => 1_000_000_000_000,
+        long_repeat_part2: "R1x100000000000000\n", part2 => 1_000_000_000_000,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day1::parse("L68\nR30\nX48\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "X"));
+
+        let err = Day1::parse("L68\nR3o\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "3o"));
+
+        let err = Day1::parse("dial size=10 start=0\nL68\nR3o\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 2, "3o"));
+
+        let err = Day1::parse("# comment\n  q5 # typo\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "q"));
+
+        let err = Day1::parse("R25x4\nL3xy\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "y"));
+        assert!(err.message.starts_with("invalid repeat count"));
+
+        let err = Day1::parse("R25x0\n").unwrap_err();
+        assert_eq!(err.message, "repeat count should be at least 1");
+
+        let err = Day1::parse("R2\nR3x9223372036854775807\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.text.as_str()),
+            (2, 4, "9223372036854775807")
+        );
+        assert_eq!(
+            err.message,
+            "repeat count 9223372036854775807 would make more than 18446744073709551615 clicks"
+        );
+        assert!(Day1::parse("R2x9223372036854775807\nR0x18446744073709551615\n").is_ok());
+
+        let err = Day1::parse("R 25\n").unwrap_err();
+        assert_eq!(err.text, " 25");
+    }
+
+    #[test]
+    fn test_rotation_line() {
+        let line: RotationLine = " r25x4 # four quarter turns ".parse().unwrap();
+        assert_eq!(line.repeat, 4);
+        assert_eq!(
+            line.rotation.map(|rotation| rotation.to_string()),
+            Some("R25".to_string())
+        );
+
+        let comment: RotationLine = "  # only a comment".parse().unwrap();
+        assert_eq!(comment.rotation, None);
+
+        assert!(matches!(
+            "R25x4".parse::<RotationCommand>(),
+            Err(DirectionParseError::Repeated(4))
+        ));
+        assert!(matches!(
+            "".parse::<RotationCommand>(),
+            Err(DirectionParseError::NoInput)
+        ));
+    }
+
+    #[test]
+    fn test_header_errors() {
+        let err = Day1::parse("dial size=10 spin=3\nL1\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 14, "spin=3"));
+
+        let err = Day1::parse("dial size=10 start=x\n").unwrap_err();
+        assert_eq!(err.text, "start=x");
+
+        let err = Day1::parse("dial size=10\nL1\n").unwrap_err();
+        assert_eq!(err.message, "dial start should be in 0..10, found 50");
+
+        let safe = Day1::parse("dial size=10 start=3 # small dial\nL3\n").unwrap();
+        assert_eq!(safe.dial, Dial::new(10, 3).unwrap());
+
+        let safe = Day1::parse("# scenario\n\ndial size=10 start=0\nL3\n").unwrap();
+        assert_eq!(safe.dial, Dial::new(10, 0).unwrap());
+
+        let err = Day1::parse("# scenario\ndial size=10 spin=3\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 14, "spin=3"));
+    }
+
+    #[test]
+    fn test_dial_new() {
+        assert_eq!(Dial::new(0, 0), Err(DialError::NoPositions));
+        assert_eq!(
+            Dial::new(5, 5),
+            Err(DialError::StartOffDial { size: 5, start: 5 })
+        );
+        assert_eq!(Dial::new(100, 50), Ok(Dial::default()));
+    }
+
+    #[test]
+    fn test_repeated_hits() {
+        for size in 1..=12 {
+            for start in 0..size {
+                let dial = Dial::new(size, start).unwrap();
+                for amount in 0..=30 {
+                    for direction in [Direction::Left, Direction::Right] {
+                        let rotation = RotationCommand { direction, amount };
+                        for target in 0..size {
+                            let mut one_by_one = dial;
+                            let (mut landed, mut passed) = (0, 0);
+                            for times in 0..=15 {
+                                assert_eq!(
+                                    dial.repeated_hits(&rotation, times, target),
+                                    (landed, passed),
+                                    "{rotation}x{times} from {start} of {size} to {target}"
+                                );
+                                let mut repeated = dial;
+                                repeated.turn_repeated(&rotation, times);
+                                assert_eq!(repeated, one_by_one);
+                                passed += u128::from(one_by_one.hits(&rotation, target));
+                                one_by_one.turn(&rotation);
+                                landed += u64::from(one_by_one.position() == target);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dial_rotate() {
+        let mut dial = Dial::new(7, 3).unwrap();
+        assert_eq!(dial.rotate(&"L3".parse().unwrap()), 1);
+        assert_eq!(dial.position(), 0);
+        assert_eq!(dial.rotate(&"L15".parse().unwrap()), 2);
+        assert_eq!(dial.position(), 6);
+        assert_eq!(dial.rotate(&"R8".parse().unwrap()), 2);
+        assert_eq!(dial.position(), 0);
+    }
+}
@@ -1,147 +1,340 @@
-use std::{num::ParseIntError, path::Path, str::FromStr};
-
-fn main() {
-    let input_file = Path::new("input.txt");
-    let part1_answer = part1(input_file);
-    println!("Part 1: {}", part1_answer);
-    let part2_answer = part2(input_file);
-    println!("Part 2: {}", part2_answer);
+use std::{
+    io::{self, BufRead, BufWriter, IsTerminal, Write},
+    path::PathBuf,
+    process::ExitCode,
+    time::Duration,
+};
+
+use aoc_common::Part;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use day1::{Dial, Milestones, RotationReader, Safe, TargetCounts, ZeroCount};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2025 day 1")]
+struct Cli {
+    #[command(flatten)]
+    safe: SafeArgs,
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-#[derive(Debug)]
-enum Direction {
-    Left,
-    Right,
+#[derive(Args)]
+struct SafeArgs {
+    /// Input file, or `-` for stdin
+    #[arg(short, long, global = true, default_value = "input.txt")]
+    input: PathBuf,
+    /// Number of positions on the dial, overriding the input's header
+    #[arg(long, global = true)]
+    size: Option<u64>,
+    /// Position the dial starts at, overriding the input's header
+    #[arg(long, global = true)]
+    start: Option<u64>,
 }
 
-#[derive(Debug)]
-#[allow(unused)]
-enum DirectionParseError {
-    BadInput(String),
-    NoInput,
-    ParseIntError(ParseIntError),
+#[derive(Subcommand)]
+enum Command {
+    /// Print the dial's position before and after every rotation
+    Trace {
+        #[arg(short, long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+    },
+    /// Find the single rotation whose flip or removal changes an answer the most
+    Analyze {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2), default_value_t = 2)]
+        part: u8,
+        /// Number of candidates to list
+        #[arg(short = 'n', long, default_value_t = 5)]
+        top: usize,
+        /// List the changes giving the lowest answers instead of the highest
+        #[arg(long)]
+        minimize: bool,
+    },
+    /// Animate the dial in the terminal: space pauses, n steps, +/- change speed, q quits
+    Animate {
+        /// Frames per second
+        #[arg(long, default_value_t = 10.0)]
+        fps: f64,
+        /// Most frames spent on one rotation, so long rotations don't drag
+        #[arg(long, default_value_t = 20)]
+        frames_per_rotation: u64,
+        /// Start paused, stepping one frame at a time with n
+        #[arg(long)]
+        paused: bool,
+    },
+    /// Count how often the dial stops on or passes each of several positions
+    Count {
+        /// Positions to count, such as `0,25,50`
+        #[arg(short, long, value_delimiter = ',', required = true)]
+        targets: Vec<u64>,
+    },
+    /// Find the first rotation after which an answer reaches each of several totals
+    First {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2), default_value_t = 2)]
+        part: u8,
+        /// Totals to look for, such as `1,10,100`
+        #[arg(value_delimiter = ',', required = true)]
+        totals: Vec<u128>,
+    },
 }
 
-impl From<ParseIntError> for DirectionParseError {
-    fn from(value: ParseIntError) -> Self {
-        DirectionParseError::ParseIntError(value)
-    }
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Csv,
+    /// One JSON object per line
+    Json,
 }
 
-impl FromStr for Direction {
-    type Err = DirectionParseError;
+impl SafeArgs {
+    /// Opens the input for streaming, with the dial from its header unless
+    /// overridden by flags.
+    fn open(&self) -> Result<(Dial, RotationReader<Box<dyn BufRead>>), String> {
+        let reader = aoc_common::open_input(&self.input).map_err(|err| err.diagnostic())?;
+        let reader = RotationReader::new(reader).map_err(|err| err.diagnostic())?;
+        let header = reader.dial();
+        let dial = Dial::new(
+            self.size.unwrap_or(header.size()),
+            self.start.unwrap_or(header.position()),
+        )
+        .map_err(|err| format!("error: {err}"))?;
+        Ok((dial, reader))
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "L" => Ok(Direction::Left),
-            "R" => Ok(Direction::Right),
-            _ => Err(DirectionParseError::BadInput(s.to_string())),
-        }
+    fn load(&self) -> Result<Safe, String> {
+        let (dial, reader) = self.open()?;
+        let rotations = reader
+            .collect::<Result<_, _>>()
+            .map_err(|err| err.diagnostic())?;
+        Ok(Safe { dial, rotations })
     }
 }
 
-#[derive(Debug)]
-struct RotationCommand {
-    direction: Direction,
-    amount: i32,
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        None => solve(&cli.safe),
+        Some(Command::Trace { format }) => cli.safe.load().map(|safe| {
+            write_output(|out| match format {
+                Format::Csv => day1::write_csv(out, &safe),
+                Format::Json => day1::write_json(out, &safe),
+            })
+        }),
+        Some(Command::Count { targets }) => count(&cli.safe, &targets),
+        Some(Command::First { part, totals }) => cli.safe.load().map(|safe| {
+            let part = part.try_into().expect("part should be validated");
+            print_milestones(&safe, part, &totals);
+            ExitCode::SUCCESS
+        }),
+        Some(Command::Animate {
+            fps,
+            frames_per_rotation,
+            paused,
+        }) => cli.safe.load().and_then(|safe| {
+            let player = Player {
+                delay: Duration::from_secs_f64(1.0 / fps.max(0.1)),
+                paused,
+            };
+            player
+                .play(&safe, frames_per_rotation)
+                .map(|()| ExitCode::SUCCESS)
+                .map_err(|err| format!("error: {err}"))
+        }),
+        Some(Command::Analyze {
+            part,
+            top,
+            minimize,
+        }) => cli.safe.load().and_then(|safe| {
+            let part = part.try_into().expect("part should be validated");
+            print_analysis(&safe, part, top, minimize).map(|()| ExitCode::SUCCESS)
+        }),
+    };
+    result.unwrap_or_else(|message| {
+        eprintln!("{message}");
+        ExitCode::FAILURE
+    })
 }
 
-impl FromStr for RotationCommand {
-    type Err = DirectionParseError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut char_iter = s.chars();
-        let dir_char = char_iter.next().ok_or(DirectionParseError::NoInput)?;
-        let direction = dir_char.to_string().parse()?;
-        let amount = char_iter.collect::<String>().parse()?;
-        Ok(RotationCommand { direction, amount })
+/// Counts both parts while streaming, so the rotations are never all in memory.
+fn solve(args: &SafeArgs) -> Result<ExitCode, String> {
+    let (mut dial, reader) = args.open()?;
+    let mut count = ZeroCount::default();
+    for rotation in reader {
+        let (rotation, times) = rotation.map_err(|err| err.diagnostic())?;
+        count.record_repeated(&mut dial, &rotation, times);
     }
+    println!("Part 1: {}", count.landed);
+    println!("Part 2: {}", count.passed);
+    Ok(ExitCode::SUCCESS)
 }
 
-fn parse_file(filename: &Path) -> Vec<RotationCommand> {
-    let data = std::fs::read_to_string(filename).expect("input file should exist");
-    data.lines()
-        .map(|line| line.parse().expect("line should be valid"))
-        .collect()
+fn count(args: &SafeArgs, targets: &[u64]) -> Result<ExitCode, String> {
+    let (mut dial, reader) = args.open()?;
+    let mut counts = TargetCounts::new(&dial, targets).map_err(|err| format!("error: {err}"))?;
+    for rotation in reader {
+        let (rotation, times) = rotation.map_err(|err| err.diagnostic())?;
+        counts.record_repeated(&mut dial, &rotation, times);
+    }
+    for count in counts.counts() {
+        println!(
+            "Target {}: landed {}, passed {}",
+            count.target, count.landed, count.passed
+        );
+    }
+    Ok(ExitCode::SUCCESS)
 }
 
-const DIAL_SIZE: i32 = 100;
-const START_POS: i32 = 50;
-
-fn part1(filename: &Path) -> i32 {
-    let rotations = parse_file(filename);
-    let mut zeros = 0;
-    let mut current_pos = START_POS;
-    for rotation in rotations {
-        let move_ammount = match rotation.direction {
-            Direction::Left => -rotation.amount,
-            Direction::Right => rotation.amount,
+fn print_analysis(safe: &Safe, part: Part, top: usize, minimize: bool) -> Result<(), String> {
+    let analysis = day1::analyze(safe).map_err(|err| format!("error: {err}"))?;
+    let baseline = analysis.baseline.answer(part);
+    println!("Part {part}: {baseline}");
+    for candidate in analysis.best(part, top, minimize) {
+        let answer = candidate.answer(part);
+        let change = if answer >= baseline {
+            format!("+{}", answer - baseline)
+        } else {
+            format!("-{}", baseline - answer)
         };
-        current_pos += move_ammount % DIAL_SIZE;
-        if current_pos >= DIAL_SIZE {
-            current_pos -= DIAL_SIZE;
-        } else if current_pos < 0 {
-            current_pos += DIAL_SIZE;
-        }
-        if current_pos == 0 {
-            zeros += 1;
+        println!(
+            "{} #{} ({}): {answer} ({change})",
+            candidate.change, candidate.index, candidate.rotation
+        );
+    }
+    Ok(())
+}
+
+fn print_milestones(safe: &Safe, part: Part, totals: &[u128]) {
+    let milestones = Milestones::new(safe);
+    for &total in totals {
+        match milestones.first(part, total) {
+            Some(milestone) => println!(
+                "Part {part} reaches {total} after #{} ({}) at {}",
+                milestone.index, milestone.rotation, milestone.position
+            ),
+            None => println!("Part {part} never reaches {total}"),
         }
     }
-    zeros
 }
 
-fn part2(filename: &Path) -> i32 {
-    let rotations = parse_file(filename);
-    let mut zeros = 0;
-    let mut current_pos = START_POS;
-    for rotation in rotations {
-        match rotation.direction {
-            Direction::Right => {
-                zeros += (current_pos + rotation.amount) / DIAL_SIZE;
-                current_pos = (current_pos + rotation.amount).rem_euclid(DIAL_SIZE);
+/// Playback state for `animate`.
+struct Player {
+    delay: Duration,
+    paused: bool,
+}
+
+const MIN_DELAY: Duration = Duration::from_millis(5);
+const MAX_DELAY: Duration = Duration::from_secs(2);
+
+impl Player {
+    fn play(mut self, safe: &Safe, frames_per_rotation: u64) -> io::Result<()> {
+        if !io::stdout().is_terminal() {
+            return Err(io::Error::other("animate needs a terminal"));
+        }
+        let mut frames = day1::frames(safe, frames_per_rotation);
+        let Some(mut frame) = frames.next() else {
+            return Ok(());
+        };
+        let mut done = false;
+        let _screen = Screen::enter()?;
+        loop {
+            self.draw(safe, &frame, done)?;
+            let step = match self.key(done)? {
+                Some(KeyCode::Char('q') | KeyCode::Esc) => return Ok(()),
+                Some(KeyCode::Char(' ')) => {
+                    self.paused = !self.paused;
+                    false
+                }
+                Some(KeyCode::Char('n') | KeyCode::Right) => true,
+                Some(KeyCode::Char('+' | '=')) => {
+                    self.delay = (self.delay / 2).max(MIN_DELAY);
+                    false
+                }
+                Some(KeyCode::Char('-')) => {
+                    self.delay = (self.delay * 2).min(MAX_DELAY);
+                    false
+                }
+                Some(_) => false,
+                None => !self.paused,
+            };
+            if step && !done {
+                match frames.next() {
+                    Some(next) => frame = next,
+                    None => done = true,
+                }
             }
-            Direction::Left => {
-                if current_pos == 0 {
-                    zeros += rotation.amount / DIAL_SIZE;
-                } else if rotation.amount >= current_pos {
-                    zeros += ((rotation.amount - current_pos) / DIAL_SIZE) + 1;
+        }
+    }
+
+    /// Waits for a key, or for the next frame to be due while playing.
+    fn key(&self, done: bool) -> io::Result<Option<KeyCode>> {
+        if self.paused || done || event::poll(self.delay)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    return Ok(Some(KeyCode::Null));
                 }
-                current_pos = (current_pos - rotation.amount).rem_euclid(DIAL_SIZE);
+                if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                    return Ok(Some(KeyCode::Esc));
+                }
+                return Ok(Some(key.code));
             }
+            return Ok(Some(KeyCode::Null));
+        }
+        Ok(None)
+    }
+
+    fn draw(&self, safe: &Safe, frame: &day1::Frame, done: bool) -> io::Result<()> {
+        let mut out = io::stdout().lock();
+        queue!(out, MoveTo(0, 0), Clear(ClearType::All))?;
+        for (row, line) in day1::render(safe, frame).iter().enumerate() {
+            queue!(out, MoveTo(0, row as u16), Print(line))?;
+        }
+        let state = if done {
+            "done"
+        } else if self.paused {
+            "paused"
+        } else {
+            "playing"
         };
+        let status = format!(
+            "{state}, {:.1} fps  [space] pause  [n] step  [+/-] speed  [q] quit",
+            1.0 / self.delay.as_secs_f64()
+        );
+        queue!(out, MoveTo(0, 22), Print(status))?;
+        out.flush()
     }
-    zeros
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        let test_input = Path::new("test_input.txt");
-        let part1 = part1(test_input);
-        assert_eq!(part1, 3);
-    }
+/// Raw mode on the alternate screen, restored when dropped.
+struct Screen;
 
-    #[test]
-    fn test_part2() {
-        let test_input = Path::new("test_input.txt");
-        let part2 = part2(test_input);
-        assert_eq!(part2, 6);
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Screen)
     }
+}
 
-    #[test]
-    fn test_part2_edgecase() {
-        let test_input = Path::new("test_edgecase.txt");
-        let part2 = part2(test_input);
-        assert_eq!(part2, 20);
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
     }
+}
 
-    #[test]
-    fn test_part2_edgecase2() {
-        let test_input = Path::new("test_edgecase2.txt");
-        let part2 = part2(test_input);
-        assert_eq!(part2, 2);
+fn write_output(write: impl FnOnce(&mut dyn Write) -> io::Result<()>) -> ExitCode {
+    let mut out = BufWriter::new(io::stdout().lock());
+    match write(&mut out).and_then(|()| out.flush()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
     }
 }
@@ -0,0 +1,169 @@
+use aoc_common::Part;
+
+use crate::{Dial, RotationCommand, Safe, ZeroCount};
+
+/// The first rotation after which an answer reached some total.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Milestone {
+    /// Position of the rotation, from 0, counting each repeat on its own; wider
+    /// than a repeat count as every line may repeat up to `u64::MAX` times.
+    pub index: u128,
+    pub rotation: RotationCommand,
+    /// Where the dial stopped after the rotation.
+    pub position: u64,
+    /// Totals up to and including the rotation.
+    pub count: ZeroCount,
+}
+
+/// The state before one entry of `safe.rotations`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Entry {
+    rotation: RotationCommand,
+    times: u64,
+    /// Rotations made before this entry, counting each repeat on its own.
+    index: u128,
+    dial: Dial,
+    count: ZeroCount,
+}
+
+impl Entry {
+    /// The dial and totals after the first `times` repeats.
+    fn after(&self, times: u64) -> (Dial, ZeroCount) {
+        let mut dial = self.dial;
+        let mut count = self.count;
+        count.record_repeated(&mut dial, &self.rotation, times);
+        (dial, count)
+    }
+}
+
+/// The dial and zero counts before and after every line of rotations, so the
+/// first rotation to reach any total is found by binary search rather than
+/// re-simulating, even inside a repeat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Milestones {
+    entries: Vec<Entry>,
+    /// Totals after each entry.
+    counts: Vec<ZeroCount>,
+}
+
+impl Milestones {
+    pub fn new(safe: &Safe) -> Self {
+        let mut dial = safe.dial;
+        let mut count = ZeroCount::default();
+        let mut index = 0;
+        let mut entries = Vec::with_capacity(safe.rotations.len());
+        let mut counts = Vec::with_capacity(safe.rotations.len());
+        for &(rotation, times) in &safe.rotations {
+            entries.push(Entry {
+                rotation,
+                times,
+                index,
+                dial,
+                count,
+            });
+            count.record_repeated(&mut dial, &rotation, times);
+            index += u128::from(times);
+            counts.push(count);
+        }
+        Milestones { entries, counts }
+    }
+
+    /// The first rotation after which the answer to `part` is at least `n`, or
+    /// `None` if the rotations never get that far.
+    pub fn first(&self, part: Part, n: u128) -> Option<Milestone> {
+        // Counts never decrease, so the entries and repeats below `n` form a prefix.
+        let entry = self.counts.partition_point(|count| count.answer(part) < n);
+        let entry = self.entries.get(entry)?;
+        // The entry's last repeat reaches `n`, so the search ends within it.
+        let (mut low, mut high) = (1, entry.times);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if entry.after(mid).1.answer(part) < n {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        let (dial, count) = entry.after(low);
+        Some(Milestone {
+            index: entry.index + u128::from(low) - 1,
+            rotation: entry.rotation,
+            position: dial.position(),
+            count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day1;
+
+    #[test]
+    fn test_first() {
+        let safe = Day1::parse(aoc_common::example_file!("test_input.txt")).unwrap();
+        let milestones = Milestones::new(&safe);
+        // L68 passes zero, L30 misses it, and R48 stops on it.
+        let first = milestones.first(Part::Two, 2).unwrap();
+        assert_eq!((first.index, first.position), (2, 0));
+        let first = milestones.first(Part::One, 1).unwrap();
+        assert_eq!((first.index, first.count.landed), (2, 1));
+        assert_eq!(milestones.first(Part::Two, 1).unwrap().index, 0);
+        assert_eq!(milestones.first(Part::One, 0).unwrap().index, 0);
+        assert_eq!(milestones.first(Part::One, 4), None);
+    }
+
+    #[test]
+    fn test_matches_linear_scan() {
+        for input in [
+            aoc_common::example_file!("test_edgecase.txt"),
+            "dial size=10 start=3\nR1x25\nL4\nR10x3\n",
+        ] {
+            let safe = Day1::parse(input).unwrap();
+            let milestones = Milestones::new(&safe);
+            let mut dial = safe.dial;
+            let mut count = ZeroCount::default();
+            let counts: Vec<ZeroCount> = safe
+                .each_rotation()
+                .map(|rotation| {
+                    count.record(&mut dial, &rotation);
+                    count
+                })
+                .collect();
+            for part in [Part::One, Part::Two] {
+                for n in 0..=25 {
+                    let expected = counts
+                        .iter()
+                        .position(|count| count.answer(part) >= n)
+                        .map(|index| index as u128);
+                    assert_eq!(
+                        milestones.first(part, n).map(|milestone| milestone.index),
+                        expected,
+                        "{input:?} part {part}, n {n}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_within_long_repeat() {
+        let safe = Day1::parse("R1x100000000000000\n").unwrap();
+        let milestones = Milestones::new(&safe);
+        // From 50, every hundredth click after the first fifty reaches zero.
+        let first = milestones.first(Part::Two, 1_000_000).unwrap();
+        assert_eq!((first.index, first.position), (99_999_949, 0));
+        assert_eq!(first.rotation.to_string(), "R1");
+    }
+
+    #[test]
+    fn test_index_past_u64() {
+        let input = "dial size=10 start=9\nR0x18446744073709551615\nR0x18446744073709551615\nR1\n";
+        let milestones = Milestones::new(&Day1::parse(input).unwrap());
+        let first = milestones.first(Part::Two, 1).unwrap();
+        assert_eq!((first.index, first.position), (2 * u128::from(u64::MAX), 0));
+        assert_eq!(first.rotation.to_string(), "R1");
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    fmt::Display,
+    io::{self, BufRead},
+};
+
+use aoc_common::{LineError, ParseError, Solution};
+
+use crate::{
+    Day1, Dial, DirectionParseError, HEADER, RotationCommand, RotationLine, is_blank, parse_header,
+};
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl StreamError {
+    /// Human readable report, including the offending source line for parse errors.
+    pub fn diagnostic(&self) -> String {
+        match self {
+            StreamError::Io(err) => format!("error: failed to read input: {err}"),
+            StreamError::Parse(err) => err.diagnostic(),
+        }
+    }
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "failed to read input: {err}"),
+            StreamError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(value: io::Error) -> Self {
+        StreamError::Io(value)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(value: ParseError) -> Self {
+        StreamError::Parse(value)
+    }
+}
+
+/// Reads rotations a line at a time, reusing one buffer and yielding each with
+/// the times it is repeated, so inputs of any length are solved in constant memory.
+pub struct RotationReader<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    /// Whether `line` holds a rotation that hasn't been yielded yet.
+    buffered: bool,
+    dial: Dial,
+}
+
+impl<R: BufRead> RotationReader<R> {
+    /// Reads the optional `dial` header, leaving the reader at the first rotation.
+    pub fn new(mut reader: R) -> Result<Self, StreamError> {
+        let mut line = String::new();
+        let mut line_number = 0;
+        // Blank and comment lines may come before the header.
+        let buffered = loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break false;
+            }
+            line_number += 1;
+            if !is_blank(trim_newline(&line)) {
+                break true;
+            }
+        };
+        let text = trim_newline(&line);
+        let (dial, buffered) = match text.strip_prefix(HEADER) {
+            Some(settings) => (parse_header(line_number, text, settings)?, false),
+            None => (Dial::default(), buffered),
+        };
+        Ok(RotationReader {
+            reader,
+            line,
+            line_number,
+            buffered,
+            dial,
+        })
+    }
+
+    /// The dial described by the header, or the default one without a header.
+    pub fn dial(&self) -> Dial {
+        self.dial
+    }
+
+    fn next_rotation(&mut self) -> Result<Option<(RotationCommand, u64)>, StreamError> {
+        loop {
+            if !self.buffered {
+                self.line.clear();
+                if self.reader.read_line(&mut self.line)? == 0 {
+                    return Ok(None);
+                }
+                self.line_number += 1;
+            }
+            self.buffered = false;
+            let line = trim_newline(&self.line);
+            let parsed: RotationLine = line.parse().map_err(|err: DirectionParseError| {
+                ParseError::on_line(
+                    Day1::DAY,
+                    self.line_number,
+                    line,
+                    err.span(line),
+                    err.to_string(),
+                )
+            })?;
+            if let Some(rotation) = parsed.rotation {
+                return Ok(Some((rotation, parsed.repeat)));
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for RotationReader<R> {
+    type Item = Result<(RotationCommand, u64), StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_rotation().transpose()
+    }
+}
+
+fn trim_newline(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ZeroCount;
+
+    fn count(input: &str) -> Result<ZeroCount, StreamError> {
+        let reader = RotationReader::new(input.as_bytes())?;
+        let mut dial = reader.dial();
+        let mut count = ZeroCount::default();
+        for rotation in reader {
+            let (rotation, times) = rotation?;
+            count.record_repeated(&mut dial, &rotation, times);
+        }
+        Ok(count)
+    }
+
+    #[test]
+    fn test_stream_matches_parse() {
+        for input in [
+            aoc_common::example_file!("test_input.txt"),
+            aoc_common::example_file!("test_edgecase.txt"),
+            "dial size=7 start=0\r\nL68\r\nR30",
+            "# scenario\n\n  r25x3 # three\nL7x1\n",
+            "# scenario\n\ndial size=10 start=0\nL10x3\nR4\n",
+            "R1x100000000000\nL7x3\n",
+            "",
+        ] {
+            let safe = Day1::parse(input).unwrap();
+            assert_eq!(count(input).unwrap(), safe.count_zeros(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_stream_error_position() {
+        let Err(StreamError::Parse(err)) = count("dial size=10 start=0\nL68\nR3o\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 2, "3o"));
+
+        let Err(StreamError::Parse(err)) = count("# intro\n\nR3x0\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 4, "0"));
+
+        let Err(StreamError::Parse(err)) = count("# intro\ndial size=10 spin=3\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 14, "spin=3"));
+
+        let Err(StreamError::Parse(err)) = count("dial size=0\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(err.message, "dial size should be at least 1");
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{Dial, DialError, RotationCommand};
+
+/// How often the dial met one target position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetCount {
+    pub target: u64,
+    /// Rotations that stopped on the target, like part 1 counts for zero.
+    pub landed: u128,
+    /// Clicks that left the dial on the target, like part 2 counts for zero.
+    pub passed: u128,
+}
+
+/// Landing and passing counts for several positions, gathered in one pass over
+/// the rotations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetCounts {
+    counts: Vec<TargetCount>,
+}
+
+impl TargetCounts {
+    pub fn new(dial: &Dial, targets: &[u64]) -> Result<Self, DialError> {
+        let counts = targets
+            .iter()
+            .map(|&target| {
+                if target >= dial.size() {
+                    return Err(DialError::TargetOffDial {
+                        size: dial.size(),
+                        target,
+                    });
+                }
+                Ok(TargetCount {
+                    target,
+                    landed: 0,
+                    passed: 0,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(TargetCounts { counts })
+    }
+
+    /// Turns the dial by `rotation` made `times` times in a row, counting every
+    /// target it passes or stops on in one step however many times that is.
+    pub fn record_repeated(&mut self, dial: &mut Dial, rotation: &RotationCommand, times: u64) {
+        for count in &mut self.counts {
+            let (landed, passed) = dial.repeated_hits(rotation, times, count.target);
+            count.landed += u128::from(landed);
+            count.passed += passed;
+        }
+        dial.turn_repeated(rotation, times);
+    }
+
+    pub fn counts(&self) -> &[TargetCount] {
+        &self.counts
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day1;
+
+    fn count_targets(input: &str, targets: &[u64]) -> Vec<TargetCount> {
+        let safe = Day1::parse(input).unwrap();
+        let mut dial = safe.dial;
+        let mut counts = TargetCounts::new(&dial, targets).unwrap();
+        for (rotation, times) in &safe.rotations {
+            counts.record_repeated(&mut dial, rotation, *times);
+        }
+        counts.counts().to_vec()
+    }
+
+    #[test]
+    fn test_zero_target_matches_parts() {
+        let input = aoc_common::example_file!("test_input.txt");
+        let counts = count_targets(input, &[0]);
+        assert_eq!((counts[0].landed, counts[0].passed), (3, 6));
+    }
+
+    #[test]
+    fn test_several_targets() {
+        // 50 -> 30 -> 90 -> 0 -> 30, only passing 25 on the last rotation.
+        let counts = count_targets("L20\nR60\nR10\nR30\n", &[0, 25, 30, 50]);
+        let summary: Vec<(u64, u128, u128)> = counts
+            .iter()
+            .map(|count| (count.target, count.landed, count.passed))
+            .collect();
+        assert_eq!(summary, vec![(0, 1, 1), (25, 0, 1), (30, 2, 2), (50, 0, 1)]);
+    }
+
+    #[test]
+    fn test_target_off_dial() {
+        let dial = Dial::new(10, 0).unwrap();
+        assert_eq!(
+            TargetCounts::new(&dial, &[3, 10]),
+            Err(DialError::TargetOffDial {
+                size: 10,
+                target: 10
+            })
+        );
+    }
+}
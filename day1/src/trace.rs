@@ -0,0 +1,115 @@
+use std::io::{self, Write};
+
+use crate::{Direction, Safe};
+
+/// What one rotation did to the dial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    /// Position of the rotation in the input, from 0.
+    pub index: usize,
+    pub direction: Direction,
+    pub amount: u64,
+    pub before: u64,
+    pub after: u64,
+    /// Whether the dial stopped on zero, which is what part 1 counts.
+    pub landed: bool,
+    /// Clicks that left the dial on zero, which is what part 2 counts.
+    pub passes: u64,
+}
+
+/// Applies every rotation to the safe's dial, yielding a [`Step`] for each.
+pub fn trace(safe: &Safe) -> impl Iterator<Item = Step> + '_ {
+    let mut dial = safe.dial;
+    safe.each_rotation()
+        .enumerate()
+        .map(move |(index, rotation)| {
+            let before = dial.position();
+            let passes = dial.rotate(&rotation);
+            Step {
+                index,
+                direction: rotation.direction,
+                amount: rotation.amount,
+                before,
+                after: dial.position(),
+                landed: dial.position() == 0,
+                passes,
+            }
+        })
+}
+
+pub fn write_csv(out: &mut dyn Write, safe: &Safe) -> io::Result<()> {
+    writeln!(out, "index,direction,amount,before,after,landed,passes")?;
+    for step in trace(safe) {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            step.index,
+            step.direction,
+            step.amount,
+            step.before,
+            step.after,
+            step.landed,
+            step.passes
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes one JSON object per line, so traces can be streamed and diffed line by line.
+pub fn write_json(out: &mut dyn Write, safe: &Safe) -> io::Result<()> {
+    for step in trace(safe) {
+        writeln!(
+            out,
+            r#"{{"index":{},"direction":"{}","amount":{},"before":{},"after":{},"landed":{},"passes":{}}}"#,
+            step.index,
+            step.direction,
+            step.amount,
+            step.before,
+            step.after,
+            step.landed,
+            step.passes
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day1;
+
+    #[test]
+    fn test_trace_totals_match_parts() {
+        let safe = Day1::parse(aoc_common::example_file!("test_input.txt")).unwrap();
+        let steps: Vec<Step> = trace(&safe).collect();
+        assert_eq!(steps.len(), 10);
+        assert_eq!(steps.iter().filter(|step| step.landed).count(), 3);
+        assert_eq!(steps.iter().map(|step| step.passes).sum::<u64>(), 6);
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let safe = Day1::parse("L68\nL30\n").unwrap();
+        let mut out = Vec::new();
+        write_csv(&mut out, &safe).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "index,direction,amount,before,after,landed,passes\n\
+             0,L,68,50,82,false,1\n\
+             1,L,30,82,52,false,0\n"
+        );
+    }
+
+    #[test]
+    fn test_write_json() {
+        let safe = Day1::parse("R48\n").unwrap();
+        let mut out = Vec::new();
+        write_json(&mut out, &safe).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"index\":0,\"direction\":\"R\",\"amount\":48,\"before\":50,\"after\":98,\"landed\":false,\"passes\":0}\n"
+        );
+    }
+}
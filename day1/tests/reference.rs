@@ -0,0 +1,165 @@
+//! Compares the dial solvers against a click-by-click simulation.
+
+use aoc_common::{Part, Solution};
+use day1::{Change, Day1, Milestones, TargetCounts};
+use proptest::prelude::*;
+
+/// Turns the dial one click at a time, returning (rotations ending on `target`,
+/// clicks ending on `target`).
+fn reference(size: u64, start: u64, target: u64, rotations: &[(char, u64)]) -> (u128, u128) {
+    let mut pos = start;
+    let mut landed = 0;
+    let mut passed = 0;
+    for &(direction, amount) in rotations {
+        // Stepping left is stepping right `size - 1` times.
+        let step = if direction == 'L' { size - 1 } else { 1 };
+        for _ in 0..amount {
+            pos = (pos + step) % size;
+            if pos == target {
+                passed += 1;
+            }
+        }
+        if pos == target {
+            landed += 1;
+        }
+    }
+    (landed, passed)
+}
+
+/// Mostly small dials, where wrapping around happens often, including size 1.
+fn dials() -> impl Strategy<Value = (u64, u64)> {
+    prop_oneof![1..=12u64, 1..=1000u64].prop_flat_map(|size| (Just(size), 0..size))
+}
+
+fn rotations() -> impl Strategy<Value = Vec<(char, u64)>> {
+    prop::collection::vec((prop_oneof![Just('L'), Just('R')], 0..=450u64), 0..40)
+}
+
+/// Rotations with how many times each line repeats them.
+fn repeated() -> impl Strategy<Value = Vec<(char, u64, u64)>> {
+    prop::collection::vec(
+        (prop_oneof![Just('L'), Just('R')], 0..=450u64, 1..=8u64),
+        0..20,
+    )
+}
+
+fn render(rotations: &[(char, u64)]) -> String {
+    rotations
+        .iter()
+        .map(|(direction, amount)| format!("{direction}{amount}\n"))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn matches_reference((size, start) in dials(), rotations in rotations()) {
+        let input = Day1::parse(&format!("dial size={size} start={start}\n{}", render(&rotations))).unwrap();
+        let (landed, passed) = reference(size, start, 0, &rotations);
+        prop_assert_eq!(Day1::part1(&input), landed);
+        prop_assert_eq!(Day1::part2(&input), passed);
+    }
+
+    #[test]
+    fn default_dial_matches_reference(rotations in rotations()) {
+        let input = Day1::parse(&render(&rotations)).unwrap();
+        let (landed, passed) = reference(100, 50, 0, &rotations);
+        prop_assert_eq!(Day1::part1(&input), landed);
+        prop_assert_eq!(Day1::part2(&input), passed);
+    }
+
+    #[test]
+    fn targets_match_reference(
+        (size, start) in dials(),
+        rotations in rotations(),
+        picks in prop::collection::vec(any::<prop::sample::Index>(), 1..5),
+    ) {
+        let safe = Day1::parse(&format!("dial size={size} start={start}\n{}", render(&rotations))).unwrap();
+        let targets: Vec<u64> = picks.iter().map(|pick| pick.index(size as usize) as u64).collect();
+        let mut dial = safe.dial;
+        let mut counts = TargetCounts::new(&dial, &targets).unwrap();
+        for (rotation, times) in &safe.rotations {
+            counts.record_repeated(&mut dial, rotation, *times);
+        }
+        for count in counts.counts() {
+            let expected = reference(size, start, count.target, &rotations);
+            prop_assert_eq!((count.landed, count.passed), expected);
+        }
+    }
+
+    #[test]
+    fn analysis_matches_resimulating((size, start) in dials(), rotations in rotations()) {
+        let header = format!("dial size={size} start={start}\n");
+        let safe = Day1::parse(&format!("{header}{}", render(&rotations))).unwrap();
+        for candidate in day1::analyze(&safe).unwrap().candidates {
+            let mut changed = rotations.clone();
+            match candidate.change {
+                Change::Flip => {
+                    let direction = &mut changed[candidate.index].0;
+                    *direction = if *direction == 'L' { 'R' } else { 'L' };
+                }
+                Change::Remove => {
+                    changed.remove(candidate.index);
+                }
+            }
+            let expected = reference(size, start, 0, &changed);
+            prop_assert_eq!((candidate.count.landed, candidate.count.passed), expected);
+        }
+    }
+
+    #[test]
+    fn milestones_match_prefixes((size, start) in dials(), rotations in rotations(), n in 0..20u128) {
+        let safe = Day1::parse(&format!("dial size={size} start={start}\n{}", render(&rotations))).unwrap();
+        let milestones = Milestones::new(&safe);
+        for part in [Part::One, Part::Two] {
+            // Shortest prefix of the rotations whose answer reaches `n`.
+            let expected = (1..=rotations.len()).find(|&len| {
+                let (landed, passed) = reference(size, start, 0, &rotations[..len]);
+                let answer = match part {
+                    Part::One => landed,
+                    Part::Two => passed,
+                };
+                answer >= n
+            });
+            prop_assert_eq!(
+                milestones.first(part, n).map(|milestone| milestone.index as usize + 1),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn repeats_match_reference(
+        (size, start) in dials(),
+        lines in repeated(),
+        target in any::<prop::sample::Index>(),
+        n in 0..20u128,
+    ) {
+        let input: String = lines
+            .iter()
+            .map(|(direction, amount, times)| format!("{direction}{amount}x{times}\n"))
+            .collect();
+        let safe = Day1::parse(&format!("dial size={size} start={start}\n{input}")).unwrap();
+        let rotations: Vec<(char, u64)> = lines
+            .iter()
+            .flat_map(|&(direction, amount, times)| (0..times).map(move |_| (direction, amount)))
+            .collect();
+        let (landed, passed) = reference(size, start, 0, &rotations);
+        prop_assert_eq!(Day1::part1(&safe), landed);
+        prop_assert_eq!(Day1::part2(&safe), passed);
+
+        let target = target.index(size as usize) as u64;
+        let mut dial = safe.dial;
+        let mut counts = TargetCounts::new(&dial, &[target]).unwrap();
+        for (rotation, times) in &safe.rotations {
+            counts.record_repeated(&mut dial, rotation, *times);
+        }
+        let count = counts.counts()[0];
+        prop_assert_eq!((count.landed, count.passed), reference(size, start, target, &rotations));
+
+        let expected = (1..=rotations.len()).find(|&len| reference(size, start, 0, &rotations[..len]).1 >= n);
+        prop_assert_eq!(
+            Milestones::new(&safe).first(Part::Two, n).map(|milestone| milestone.index as usize + 1),
+            expected
+        );
+    }
+}
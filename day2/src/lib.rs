@@ -0,0 +1,276 @@
+mod normalize;
+mod repeats;
+mod rule;
+mod scan;
+
+use std::{
+    fmt::Display,
+    io::{self, Write},
+};
+
+use aoc_common::{
+    ParseError, Solution,
+    generate::{Rng, Size},
+};
+
+pub use normalize::{Normalized, Overlap, Reversed, ReversedRange, normalize};
+pub use rule::{Rule, RuleError};
+pub use scan::{CHUNK_SIZE, scan_sum};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdRange {
+    low: u128,
+    high: u128,
+}
+
+impl Display for IdRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.low, self.high)
+    }
+}
+
+impl IdRange {
+    /// A range of IDs from `low` to `high` inclusive, which is empty if `low` is
+    /// the larger.
+    pub fn new(low: u128, high: u128) -> Self {
+        IdRange { low, high }
+    }
+
+    pub fn low(&self) -> u128 {
+        self.low
+    }
+
+    pub fn high(&self) -> u128 {
+        self.high
+    }
+
+    /// The IDs `is_invalid` accepts, checked one at a time as the iterator is
+    /// advanced, so the range should be small enough to scan.
+    pub fn invalid_ids<F>(&self, is_invalid: F) -> impl DoubleEndedIterator<Item = u128>
+    where
+        F: Fn(u128) -> bool,
+    {
+        (self.low..=self.high).filter(move |&id| is_invalid(id))
+    }
+
+    pub fn count_invalid<F: Fn(u128) -> bool>(&self, is_invalid: F) -> u128 {
+        self.invalid_ids(is_invalid).map(|_| 1).sum()
+    }
+
+    /// Sum of the IDs `is_invalid` accepts, or `None` if it overflows.
+    pub fn sum_invalid_ids<F: Fn(u128) -> bool>(&self, is_invalid: F) -> Option<u128> {
+        self.invalid_ids(is_invalid)
+            .try_fold(0u128, |sum, id| sum.checked_add(id))
+    }
+
+    pub fn min_invalid<F: Fn(u128) -> bool>(&self, is_invalid: F) -> Option<u128> {
+        self.invalid_ids(is_invalid).next()
+    }
+
+    pub fn max_invalid<F: Fn(u128) -> bool>(&self, is_invalid: F) -> Option<u128> {
+        self.invalid_ids(is_invalid).next_back()
+    }
+
+    /// Sum of the IDs `rule` calls invalid, or `None` if it overflows, in time
+    /// depending on the number of digits rather than the width of the range.
+    pub fn sum_by_rule(&self, rule: &Rule) -> Option<u128> {
+        repeats::sum_invalid(self.low, self.high, rule)
+    }
+}
+
+/// Sum of the IDs `rule` calls invalid across all the ranges, or `None` if it
+/// overflows.
+pub fn sum_by_rule(ranges: &[IdRange], rule: &Rule) -> Option<u128> {
+    ranges.iter().try_fold(0u128, |sum, range| {
+        sum.checked_add(range.sum_by_rule(rule)?)
+    })
+}
+
+/// An answer: the sum of the invalid IDs, or a note that it overflowed, so
+/// inputs with too many large invalid IDs still get an answer rather than a panic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdSum {
+    Total(u128),
+    Overflow,
+}
+
+impl IdSum {
+    pub fn total(self) -> Option<u128> {
+        match self {
+            IdSum::Total(total) => Some(total),
+            IdSum::Overflow => None,
+        }
+    }
+}
+
+impl From<Option<u128>> for IdSum {
+    fn from(value: Option<u128>) -> Self {
+        value.map_or(IdSum::Overflow, IdSum::Total)
+    }
+}
+
+impl Display for IdSum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdSum::Total(total) => write!(f, "{total}"),
+            IdSum::Overflow => write!(f, "overflow (more than {})", u128::MAX),
+        }
+    }
+}
+
+/// Whether `id` is a block of digits repeated exactly twice.
+pub fn is_invalid_id_part1(id: u128) -> bool {
+    Rule::PART1.is_invalid(id)
+}
+
+/// Whether `id` is a block of digits repeated at least twice.
+pub fn is_invalid_id_part2(id: u128) -> bool {
+    Rule::PART2.is_invalid(id)
+}
+
+fn parse_id(input: &str, id: &str) -> Result<u128, ParseError> {
+    let id = id.trim();
+    id.parse()
+        .map_err(|err| ParseError::new(Day2::DAY, input, id, format!("invalid id '{id}': {err}")))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<IdRange>;
+    type Answer1 = IdSum;
+    type Answer2 = IdSum;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split(',')
+            .map(|range| {
+                let range = range.trim();
+                let (low, high) = range.split_once('-').ok_or_else(|| {
+                    ParseError::new(
+                        Self::DAY,
+                        input,
+                        range,
+                        format!("expected a range like '11-22', found '{range}'"),
+                    )
+                })?;
+                Ok(IdRange {
+                    low: parse_id(input, low)?,
+                    high: parse_id(input, high)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(ranges: &Self::Input) -> IdSum {
+        sum_by_rule(ranges, &Rule::PART1).into()
+    }
+
+    fn part2(ranges: &Self::Input) -> IdSum {
+        sum_by_rule(ranges, &Rule::PART2).into()
+    }
+}
+
+/// Writes `size.count` comma-separated ranges of IDs with up to ten digits, each
+/// spanning fewer than 100,000 IDs.
+pub fn generate(out: &mut dyn Write, rng: &mut Rng, size: Size) -> io::Result<()> {
+    for i in 0..size.count {
+        if i > 0 {
+            write!(out, ",")?;
+        }
+        let digits = rng.range(1..=10) as u32;
+        let low = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+        let high = low + rng.below(100_000);
+        write!(out, "{low}-{high}")?;
+    }
+    writeln!(out)
+}
+
+aoc_common::examples! {
+    Day2 {
+        example_part1: example_file!("test_input.txt"), part1 => IdSum::Total(1227775554),
+        example_part2: example_file!("test_input.txt"), part2 => IdSum::Total(4174379265),
+        wide_range_part1: "1-1000000000000000", part1 => IdSum::Total(495495500040945040950),
+        wide_range_part2: "1-1000000000000000", part2 => IdSum::Total(545494996572039176355),
+        beyond_u64_part1: "100000000000000000000-999999999999999999999999", part1 => IdSum::Total(495495000000045449999999505000000000),
+        overflow_part1: "1-340282366920938463463374607431768211455", part1 => IdSum::Overflow,
+        overflow_part2: "1-340282366920938463463374607431768211455", part2 => IdSum::Overflow,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Part;
+
+    use super::*;
+
+    #[test]
+    fn test_is_invalid_id_part1() {
+        assert!(!is_invalid_id_part1(10));
+        assert!(is_invalid_id_part1(11));
+        assert!(is_invalid_id_part1(99));
+    }
+
+    #[test]
+    fn test_is_invalid_id_part2() {
+        assert!(is_invalid_id_part2(1010));
+        assert!(!is_invalid_id_part2(10));
+        assert!(is_invalid_id_part2(11));
+        assert!(is_invalid_id_part2(22));
+        assert!(is_invalid_id_part2(99));
+        assert!(is_invalid_id_part2(999));
+        assert!(is_invalid_id_part2(1010));
+        assert!(is_invalid_id_part2(2121212121));
+        assert!(is_invalid_id_part2(38593859));
+        assert!(is_invalid_id_part2(1188511885));
+    }
+
+    #[test]
+    fn test_range_helpers() {
+        let range = IdRange {
+            low: 95,
+            high: 1015,
+        };
+        let ids: Vec<u128> = range.invalid_ids(is_invalid_id_part2).collect();
+        assert_eq!(ids, [99, 111, 222, 333, 444, 555, 666, 777, 888, 999, 1010]);
+        assert_eq!(range.count_invalid(is_invalid_id_part2), 11);
+        assert_eq!(
+            range.sum_invalid_ids(is_invalid_id_part2),
+            Some(ids.iter().sum())
+        );
+        assert_eq!(range.min_invalid(is_invalid_id_part1), Some(99));
+        assert_eq!(range.max_invalid(is_invalid_id_part1), Some(1010));
+        assert_eq!(range.max_invalid(|id| id > 2000), None);
+
+        let top = IdRange {
+            low: u128::MAX - 1,
+            high: u128::MAX,
+        };
+        assert_eq!(top.sum_invalid_ids(|_| true), None);
+        let all = IdRange {
+            low: 1,
+            high: u128::MAX,
+        };
+        assert_eq!(all.sum_by_rule(&Rule::PART2), None);
+    }
+
+    #[test]
+    fn test_overflow_display() {
+        let ranges = Day2::parse("1-340282366920938463463374607431768211455").unwrap();
+        assert_eq!(
+            Day2::solve(&ranges, Part::Two),
+            "overflow (more than 340282366920938463463374607431768211455)"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day2::parse("11-22,95115").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 7, "95115"));
+
+        let err = Day2::parse("11-22,\n95-1x5").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "1x5"));
+    }
+}
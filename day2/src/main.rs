@@ -1,127 +1,150 @@
-use std::path::Path;
+use std::{num::NonZeroUsize, path::PathBuf, process::ExitCode, thread};
 
-fn main() {
-    let input_file = Path::new("input.txt");
-    let part1_answer = part1(input_file);
-    println!("Part 1: {}", part1_answer);
-    let part2_answer = part2(input_file);
-    println!("Part 2: {}", part2_answer);
-}
+use aoc_common::Solution;
+use clap::{Args, Parser, ValueEnum};
+use day2::{Day2, IdRange, Reversed, Rule, RuleError};
 
-struct IdRange {
-    low: u64,
-    high: u64,
+#[derive(Parser)]
+#[command(about = "Advent of Code 2025 day 2")]
+struct Cli {
+    /// Input file, or `-` for stdin
+    #[arg(short, long, default_value = "input.txt")]
+    input: PathBuf,
+    /// Merge overlapping ranges first, so IDs in several ranges count once
+    #[arg(long)]
+    normalize: bool,
+    /// What normalizing does with a range written high to low
+    #[arg(long, value_enum, default_value_t = ReversedArg::Reject, requires = "normalize")]
+    reversed: ReversedArg,
+    /// Check every ID one at a time instead of summing them arithmetically
+    #[arg(long)]
+    scan: bool,
+    /// Threads to scan on, defaulting to one per core
+    #[arg(short = 'j', long, requires = "scan")]
+    threads: Option<NonZeroUsize>,
+    #[command(flatten)]
+    rule: RuleArgs,
 }
 
-impl IdRange {
-    pub fn get_invalid_ids(&self, is_invalid_id: &dyn Fn(u64) -> bool) -> Vec<u64> {
-        let mut invalid_ids = Vec::new();
-        for i in self.low..=self.high {
-            if is_invalid_id(i) {
-                invalid_ids.push(i);
-            }
-        }
-        invalid_ids
-    }
+#[derive(Clone, Copy, ValueEnum)]
+enum ReversedArg {
+    /// Fail, pointing at the reversed range
+    Reject,
+    /// Swap its ends
+    Swap,
 }
 
-fn is_invalid_id_part1(id: u64) -> bool {
-    let digit_string = id.to_string();
-    if !digit_string.len().is_multiple_of(2) {
-        return false;
-    }
-    let half = digit_string.len() / 2;
-    digit_string[0..half] == digit_string[half..digit_string.len()]
+/// Any of these sums the invalid IDs under that one rule instead of solving
+/// both parts.
+#[derive(Args)]
+struct RuleArgs {
+    /// Rule to start from, adjusted by the other flags
+    #[arg(long, value_enum)]
+    preset: Option<Preset>,
+    /// Base the IDs are written in: 2, 8, 10 or 16
+    #[arg(long)]
+    base: Option<u32>,
+    /// Fewest times a block must repeat
+    #[arg(long)]
+    min_repeats: Option<u32>,
+    /// Most times a block may repeat
+    #[arg(long)]
+    max_repeats: Option<u32>,
+    /// Let IDs be padded with zeros to make them repeat, so 101 is 01 twice
+    #[arg(long)]
+    leading_zeros: bool,
 }
 
-fn is_invalid_id_part2(id: u64) -> bool {
-    let digit_string = id.to_string();
-    let half_len = digit_string.len() / 2;
+#[derive(Clone, Copy, ValueEnum)]
+enum Preset {
+    /// A block repeated exactly twice
+    Part1,
+    /// A block repeated any number of times
+    Part2,
+}
 
-    for chunk_size in (1..=half_len).rev() {
-        let mut chunks = digit_string.as_bytes().chunks(chunk_size);
-        let first = chunks.next().expect("first chunk should exist");
-        if chunks.all(|chunk| first == chunk) {
-            return true;
+impl RuleArgs {
+    /// The rule the flags describe, or `None` if none were given.
+    fn rule(&self) -> Result<Option<Rule>, RuleError> {
+        if self.preset.is_none()
+            && self.base.is_none()
+            && self.min_repeats.is_none()
+            && self.max_repeats.is_none()
+            && !self.leading_zeros
+        {
+            return Ok(None);
         }
+        let preset = match self.preset {
+            Some(Preset::Part1) => Rule::PART1,
+            Some(Preset::Part2) | None => Rule::PART2,
+        };
+        Rule::new(
+            self.base.unwrap_or(preset.base()),
+            self.min_repeats.unwrap_or(preset.min_repeats()),
+            self.max_repeats.or(preset.max_repeats()),
+            self.leading_zeros || preset.leading_zeros(),
+        )
+        .map(Some)
     }
-    false
-}
-
-fn parse_file(filename: &Path) -> Vec<IdRange> {
-    let text = std::fs::read_to_string(filename).expect("input file should exist");
-    text.split(',')
-        .map(|range| {
-            let mut split_hiphen = range.split('-');
-            let low = split_hiphen
-                .next()
-                .expect("low should exist")
-                .trim_end()
-                .parse()
-                .expect("should be number");
-            let high = split_hiphen
-                .next()
-                .expect("high should exist")
-                .trim_end()
-                .parse()
-                .expect("should be number");
-            IdRange { low, high }
-        })
-        .collect()
 }
 
-fn part1(filename: &Path) -> u64 {
-    let ranges = parse_file(filename);
-    ranges
-        .iter()
-        .flat_map(|r| r.get_invalid_ids(&is_invalid_id_part1))
-        .sum()
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    run(&cli).unwrap_or_else(|message| {
+        eprintln!("{message}");
+        ExitCode::FAILURE
+    })
 }
 
-fn part2(filename: &Path) -> u64 {
-    let ranges = parse_file(filename);
-    ranges
-        .iter()
-        .flat_map(|r| r.get_invalid_ids(&is_invalid_id_part2))
-        .sum()
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        let test_input = Path::new("test_input.txt");
-        let part1 = part1(test_input);
-        assert_eq!(part1, 1227775554);
+fn run(cli: &Cli) -> Result<ExitCode, String> {
+    let rule = cli.rule.rule().map_err(|err| format!("error: {err}"))?;
+    let mut ranges = Day2::parse_file(&cli.input).map_err(|err| err.diagnostic())?;
+    if cli.normalize {
+        ranges = normalize(&ranges, cli.reversed)?;
     }
-
-    #[test]
-    fn test_part2() {
-        let test_input = Path::new("test_input.txt");
-        let part2 = part2(test_input);
-        assert_eq!(part2, 4174379265);
+    let threads = cli
+        .threads
+        .unwrap_or_else(|| thread::available_parallelism().unwrap_or(NonZeroUsize::MIN));
+    let sum = |rule: &Rule| {
+        if cli.scan {
+            day2::scan_sum(&ranges, threads, |id| rule.is_invalid(id))
+        } else {
+            day2::sum_by_rule(&ranges, rule)
+        }
+        .ok_or("error: invalid ID sum doesn't fit in a u128")
+    };
+    match rule {
+        Some(rule) => println!("Invalid ID sum: {}", sum(&rule)?),
+        None => {
+            println!("Part 1: {}", sum(&Rule::PART1)?);
+            println!("Part 2: {}", sum(&Rule::PART2)?);
+        }
     }
+    Ok(ExitCode::SUCCESS)
+}
 
-    #[test]
-    fn test_is_invalid_id_part1() {
-        assert!(!is_invalid_id_part1(10));
-        assert!(is_invalid_id_part1(11));
-        assert!(is_invalid_id_part1(99));
+/// Merges the ranges, warning about any that were swapped or overlapped.
+fn normalize(ranges: &[IdRange], reversed: ReversedArg) -> Result<Vec<IdRange>, String> {
+    let reversed = match reversed {
+        ReversedArg::Reject => Reversed::Reject,
+        ReversedArg::Swap => Reversed::Swap,
+    };
+    let normalized = day2::normalize(ranges, reversed).map_err(|err| format!("error: {err}"))?;
+    for &index in &normalized.swapped {
+        eprintln!(
+            "warning: swapped reversed range {index} ({})",
+            ranges[index]
+        );
     }
-
-    #[test]
-    fn test_is_invalid_id_part2() {
-        assert!(is_invalid_id_part2(1010));
-        assert!(!is_invalid_id_part2(10));
-        assert!(is_invalid_id_part2(11));
-        assert!(is_invalid_id_part2(22));
-        assert!(is_invalid_id_part2(99));
-        assert!(is_invalid_id_part2(999));
-        assert!(is_invalid_id_part2(1010));
-        assert!(is_invalid_id_part2(2121212121));
-        assert!(is_invalid_id_part2(38593859));
-        assert!(is_invalid_id_part2(1188511885));
+    for overlap in &normalized.overlaps {
+        eprintln!(
+            "warning: ranges {} ({}) and {} ({}) share {} IDs",
+            overlap.first,
+            ranges[overlap.first],
+            overlap.second,
+            ranges[overlap.second],
+            overlap.shared
+        );
     }
+    Ok(normalized.ranges)
 }
@@ -0,0 +1,171 @@
+use std::fmt::Display;
+
+use crate::IdRange;
+
+/// What to do with a range written high to low, like `22-11`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reversed {
+    Reject,
+    Swap,
+}
+
+/// Two input ranges covering some of the same IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overlap {
+    /// Positions of the ranges in the input, from 0.
+    pub first: usize,
+    pub second: usize,
+    /// IDs the two have in common.
+    pub shared: u128,
+}
+
+/// The input's ranges, sorted and merged so every ID appears at most once.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Normalized {
+    pub ranges: Vec<IdRange>,
+    /// Positions of the reversed ranges that were swapped.
+    pub swapped: Vec<usize>,
+    /// Each range that overlapped an earlier-starting one, paired with the one
+    /// reaching furthest, so `shared` adds up to the IDs that were duplicated.
+    pub overlaps: Vec<Overlap>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReversedRange {
+    pub index: usize,
+    pub range: IdRange,
+}
+
+impl Display for ReversedRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "range {} ({}) is reversed, expected its low ID first",
+            self.index, self.range
+        )
+    }
+}
+
+impl std::error::Error for ReversedRange {}
+
+pub fn normalize(ranges: &[IdRange], reversed: Reversed) -> Result<Normalized, ReversedRange> {
+    let mut swapped = Vec::new();
+    let mut sorted = Vec::with_capacity(ranges.len());
+    for (index, &range) in ranges.iter().enumerate() {
+        if range.low() <= range.high() {
+            sorted.push((index, range));
+        } else if reversed == Reversed::Swap {
+            swapped.push(index);
+            sorted.push((index, IdRange::new(range.high(), range.low())));
+        } else {
+            return Err(ReversedRange { index, range });
+        }
+    }
+    sorted.sort_by_key(|&(index, range)| (range.low(), index));
+
+    let mut merged: Vec<IdRange> = Vec::new();
+    let mut overlaps = Vec::new();
+    // The input range reaching highest in the last merged range.
+    let mut furthest = 0;
+    for (index, range) in sorted {
+        let Some(last) = merged.last_mut() else {
+            merged.push(range);
+            furthest = index;
+            continue;
+        };
+        if range.low() <= last.high() {
+            overlaps.push(Overlap {
+                first: furthest,
+                second: index,
+                shared: last.high().min(range.high()) - range.low() + 1,
+            });
+        }
+        // Touching ranges merge too, as they cover the same IDs either way.
+        if range.low() <= last.high().saturating_add(1) {
+            if range.high() > last.high() {
+                *last = IdRange::new(last.low(), range.high());
+                furthest = index;
+            }
+        } else {
+            merged.push(range);
+            furthest = index;
+        }
+    }
+    Ok(Normalized {
+        ranges: merged,
+        swapped,
+        overlaps,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::{Day2, IdSum};
+
+    fn bounds(ranges: &[IdRange]) -> Vec<(u128, u128)> {
+        ranges
+            .iter()
+            .map(|range| (range.low(), range.high()))
+            .collect()
+    }
+
+    #[test]
+    fn test_merges_overlaps() {
+        let ranges = Day2::parse("30-40,11-22,15-35,41-45,50-50,38-39").unwrap();
+        let normalized = normalize(&ranges, Reversed::Reject).unwrap();
+        assert_eq!(bounds(&normalized.ranges), [(11, 45), (50, 50)]);
+        assert_eq!(
+            normalized.overlaps,
+            [
+                Overlap {
+                    first: 1,
+                    second: 2,
+                    shared: 8
+                },
+                Overlap {
+                    first: 2,
+                    second: 0,
+                    shared: 6
+                },
+                Overlap {
+                    first: 0,
+                    second: 5,
+                    shared: 2
+                },
+            ]
+        );
+        assert!(normalized.swapped.is_empty());
+    }
+
+    #[test]
+    fn test_reversed() {
+        let ranges = Day2::parse("11-22,99-95").unwrap();
+        let err = normalize(&ranges, Reversed::Reject).unwrap_err();
+        assert_eq!(err.index, 1);
+        assert_eq!(
+            err.to_string(),
+            "range 1 (99-95) is reversed, expected its low ID first"
+        );
+
+        let normalized = normalize(&ranges, Reversed::Swap).unwrap();
+        assert_eq!(bounds(&normalized.ranges), [(11, 22), (95, 99)]);
+        assert_eq!(normalized.swapped, [1]);
+    }
+
+    #[test]
+    fn test_duplicates_counted_once() {
+        let ranges = Day2::parse("11-22,11-22,20-25").unwrap();
+        assert_eq!(Day2::part1(&ranges), IdSum::Total(11 + 22 + 11 + 22 + 22));
+        let normalized = normalize(&ranges, Reversed::Reject).unwrap();
+        assert_eq!(Day2::part1(&normalized.ranges), IdSum::Total(11 + 22));
+        let shared: u128 = normalized
+            .overlaps
+            .iter()
+            .map(|overlap| overlap.shared)
+            .sum();
+        assert_eq!(shared, 12 + 3);
+    }
+}
@@ -0,0 +1,147 @@
+//! Sums of repeated-block IDs worked out arithmetically, without visiting every ID.
+//!
+//! An ID of `width` digits made of a `period`-digit block repeated is the block
+//! times `1 + base^period + base^(2 * period) + ...`, so those IDs in a range are
+//! just the multiples of that number, summed as an arithmetic series. An ID can
+//! repeat with several periods (`1111` is `1` or `11` repeated), so each one is
+//! counted once, under its shortest period. Zero-padded IDs only ever repeat at
+//! one width, so widths never need deduplicating.
+
+use crate::Rule;
+
+/// Sum of the IDs in `low..=high` that `rule` calls invalid, or `None` if it
+/// overflows.
+pub(crate) fn sum_invalid(low: u128, high: u128, rule: &Rule) -> Option<u128> {
+    let base = u128::from(rule.base());
+    let mut total: u128 = 0;
+    // Padding is less than half the width, as the first block can't be all zeros.
+    let widths = if rule.leading_zeros() {
+        2..2 * digits(high, base)
+    } else {
+        digits(low, base)..digits(high, base) + 1
+    };
+    for width in widths {
+        let smallest = if rule.leading_zeros() {
+            1
+        } else {
+            base.pow(width - 1)
+        };
+        let low = low.max(smallest);
+        let high = base
+            .checked_pow(width)
+            .map_or(high, |limit| high.min(limit - 1));
+        if low > high {
+            continue;
+        }
+        let periods: Vec<u32> = (1..width).filter(|period| width % period == 0).collect();
+        // Sums of the IDs whose shortest period is `periods[i]`, which are the
+        // IDs repeating with that period less those repeating with a shorter
+        // period dividing it.
+        let mut shortest: Vec<u128> = Vec::with_capacity(periods.len());
+        for (i, &period) in periods.iter().enumerate() {
+            let all = match repunit(base, width, period) {
+                Some(step) => sum_multiples(low, high, step)?,
+                None => 0,
+            };
+            let shorter: u128 = periods[..i]
+                .iter()
+                .zip(&shortest)
+                .filter(|&(shorter, _)| period % shorter == 0)
+                .map(|(_, sum)| sum)
+                .sum();
+            shortest.push(all - shorter);
+        }
+        // An ID whose shortest period is `period` also repeats with every
+        // longer period it divides.
+        for (&period, sum) in periods.iter().zip(&shortest) {
+            let counts = periods
+                .iter()
+                .filter(|&&longer| longer % period == 0)
+                .any(|&longer| rule.allows(width / longer));
+            if counts {
+                total = total.checked_add(*sum)?;
+            }
+        }
+    }
+    Some(total)
+}
+
+fn digits(n: u128, base: u128) -> u32 {
+    n.checked_ilog(base).unwrap_or(0) + 1
+}
+
+/// `1 + base^period + ...` up to `width` digits, which repeats a block
+/// `width / period` times when multiplied by it, or `None` if it doesn't fit.
+fn repunit(base: u128, width: u32, period: u32) -> Option<u128> {
+    let shift = base.checked_pow(period)?;
+    let mut repunit: u128 = 1;
+    for _ in 1..width / period {
+        repunit = repunit.checked_mul(shift)?.checked_add(1)?;
+    }
+    Some(repunit)
+}
+
+/// Sum of the multiples of `step` in `low..=high`, or `None` if it overflows.
+fn sum_multiples(low: u128, high: u128, step: u128) -> Option<u128> {
+    let first = low.div_ceil(step);
+    let last = high / step;
+    if first > last {
+        return Some(0);
+    }
+    let count = last - first + 1;
+    let ends = first.checked_add(last)?;
+    // One of `count` and `first + last` is even, so halve that one first.
+    let series = if count.is_multiple_of(2) {
+        (count / 2).checked_mul(ends)?
+    } else {
+        (ends / 2).checked_mul(count)?
+    };
+    series.checked_mul(step)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn brute_force(low: u128, high: u128, rule: &Rule) -> u128 {
+        (low..=high).filter(|&id| rule.is_invalid(id)).sum()
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let rules = [
+            Rule::PART1,
+            Rule::PART2,
+            Rule::new(2, 2, None, false).unwrap(),
+            Rule::new(2, 3, Some(4), true).unwrap(),
+            Rule::new(8, 2, Some(2), true).unwrap(),
+            Rule::new(16, 2, None, true).unwrap(),
+            Rule::new(10, 2, None, true).unwrap(),
+            Rule::new(10, 3, None, false).unwrap(),
+        ];
+        for (low, high) in [
+            (0, 0),
+            (1, 9),
+            (1, 40_000),
+            (95, 115),
+            (998_000, 1_012_000),
+            (7, 3),
+        ] {
+            for rule in &rules {
+                assert_eq!(
+                    sum_invalid(low, high, rule),
+                    Some(brute_force(low, high, rule)),
+                    "{low}-{high} {rule:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sum_multiples() {
+        assert_eq!(sum_multiples(10, 30, 7), Some(14 + 21 + 28));
+        assert_eq!(sum_multiples(15, 20, 7), Some(0));
+        assert_eq!(sum_multiples(0, 0, 3), Some(0));
+        assert_eq!(sum_multiples(1, u128::MAX, 1), None);
+    }
+}
@@ -0,0 +1,179 @@
+use std::fmt::Display;
+
+/// Which IDs count as invalid: those written as a block of digits repeated a
+/// number of times in `min_repeats..=max_repeats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    base: u32,
+    min_repeats: u32,
+    max_repeats: Option<u32>,
+    /// Whether an ID may be padded with zeros to make it repeat, so `101` is
+    /// `01` twice.
+    leading_zeros: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RuleError {
+    UnsupportedBase(u32),
+    TooFewRepeats(u32),
+    NoRepeats { min: u32, max: u32 },
+}
+
+impl Display for RuleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleError::UnsupportedBase(base) => {
+                write!(f, "base should be 2, 8, 10 or 16, found {base}")
+            }
+            RuleError::TooFewRepeats(min) => {
+                write!(f, "minimum repeats should be at least 2, found {min}")
+            }
+            RuleError::NoRepeats { min, max } => write!(
+                f,
+                "maximum repeats should be at least the minimum {min}, found {max}"
+            ),
+        }
+    }
+}
+
+impl Rule {
+    pub const BASES: [u32; 4] = [2, 8, 10, 16];
+
+    /// Part 1: a decimal block repeated exactly twice.
+    pub const PART1: Rule = Rule {
+        base: 10,
+        min_repeats: 2,
+        max_repeats: Some(2),
+        leading_zeros: false,
+    };
+
+    /// Part 2: a decimal block repeated any number of times.
+    pub const PART2: Rule = Rule {
+        base: 10,
+        min_repeats: 2,
+        max_repeats: None,
+        leading_zeros: false,
+    };
+
+    pub fn new(
+        base: u32,
+        min_repeats: u32,
+        max_repeats: Option<u32>,
+        leading_zeros: bool,
+    ) -> Result<Self, RuleError> {
+        if !Self::BASES.contains(&base) {
+            return Err(RuleError::UnsupportedBase(base));
+        }
+        if min_repeats < 2 {
+            return Err(RuleError::TooFewRepeats(min_repeats));
+        }
+        if let Some(max) = max_repeats.filter(|&max| max < min_repeats) {
+            return Err(RuleError::NoRepeats {
+                min: min_repeats,
+                max,
+            });
+        }
+        Ok(Rule {
+            base,
+            min_repeats,
+            max_repeats,
+            leading_zeros,
+        })
+    }
+
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    pub fn min_repeats(&self) -> u32 {
+        self.min_repeats
+    }
+
+    /// The most repeats that count, or `None` for no limit.
+    pub fn max_repeats(&self) -> Option<u32> {
+        self.max_repeats
+    }
+
+    pub fn leading_zeros(&self) -> bool {
+        self.leading_zeros
+    }
+
+    /// Whether a block repeated `times` times counts.
+    pub fn allows(&self, times: u32) -> bool {
+        times >= self.min_repeats && self.max_repeats.is_none_or(|max| times <= max)
+    }
+
+    /// Checks `id` by writing out its digits, for ranges small enough to scan.
+    pub fn is_invalid(&self, id: u128) -> bool {
+        let mut digits = Vec::new();
+        let mut rest = id;
+        while rest > 0 {
+            digits.push((rest % u128::from(self.base)) as u8);
+            rest /= u128::from(self.base);
+        }
+        digits.reverse();
+        let len = digits.len();
+        // A padded ID's first block can't be all zeros, so it is padded by less
+        // than half its width.
+        let widths = if self.leading_zeros {
+            len..2 * len
+        } else {
+            len..len + 1
+        };
+        widths.into_iter().any(|width| {
+            let mut padded = vec![0; width - len];
+            padded.extend(&digits);
+            (1..width)
+                .filter(|&period| width.is_multiple_of(period))
+                .filter(|&period| self.allows((width / period) as u32))
+                .any(|period| {
+                    padded
+                        .chunks(period)
+                        .all(|chunk| chunk == &padded[..period])
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Rule::new(10, 2, Some(2), false), Ok(Rule::PART1));
+        assert_eq!(Rule::new(10, 2, None, false), Ok(Rule::PART2));
+        assert_eq!(
+            Rule::new(3, 2, None, false),
+            Err(RuleError::UnsupportedBase(3))
+        );
+        assert_eq!(
+            Rule::new(10, 1, None, false),
+            Err(RuleError::TooFewRepeats(1))
+        );
+        assert_eq!(
+            Rule::new(10, 3, Some(2), false),
+            Err(RuleError::NoRepeats { min: 3, max: 2 })
+        );
+    }
+
+    #[test]
+    fn test_is_invalid() {
+        let hex = Rule::new(16, 2, None, false).unwrap();
+        assert!(hex.is_invalid(0xabab));
+        assert!(!hex.is_invalid(0xaba));
+
+        let three = Rule::new(10, 3, Some(3), false).unwrap();
+        assert!(three.is_invalid(121212));
+        assert!(!three.is_invalid(1212));
+        assert!(three.is_invalid(111));
+        assert!(!three.is_invalid(1111));
+
+        let padded = Rule::new(10, 2, Some(2), true).unwrap();
+        assert!(padded.is_invalid(101));
+        assert!(padded.is_invalid(1010));
+        assert!(padded.is_invalid(1001));
+        assert!(!padded.is_invalid(1002));
+        assert!(!Rule::PART1.is_invalid(101));
+    }
+}
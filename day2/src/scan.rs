@@ -0,0 +1,126 @@
+use std::{num::NonZeroUsize, sync::Mutex, thread};
+
+use crate::IdRange;
+
+/// IDs a thread checks before taking more work.
+pub const CHUNK_SIZE: u128 = 1 << 16;
+
+/// Sum of the IDs `is_invalid` accepts in every range, checking each ID on
+/// `threads` threads, or `None` if it overflows.
+///
+/// Ranges are split into chunks of [`CHUNK_SIZE`] IDs that threads take in
+/// turn, so one wide range keeps every thread busy. The sums are exact, so the
+/// result is the same for any number of threads.
+pub fn scan_sum<F>(ranges: &[IdRange], threads: NonZeroUsize, is_invalid: F) -> Option<u128>
+where
+    F: Fn(u128) -> bool + Sync,
+{
+    scan_in_chunks(ranges, threads, CHUNK_SIZE, &is_invalid)
+}
+
+fn scan_in_chunks<F>(
+    ranges: &[IdRange],
+    threads: NonZeroUsize,
+    chunk_size: u128,
+    is_invalid: &F,
+) -> Option<u128>
+where
+    F: Fn(u128) -> bool + Sync,
+{
+    // Chunks before each range, so a chunk number leads back to its range.
+    let mut starts = Vec::with_capacity(ranges.len());
+    let mut chunks: u128 = 0;
+    for range in ranges {
+        starts.push(chunks);
+        if range.low() <= range.high() {
+            chunks += (range.high() - range.low()) / chunk_size + 1;
+        }
+    }
+    let next = Mutex::new(0);
+    let worker = || {
+        let mut sum: u128 = 0;
+        loop {
+            let chunk = {
+                let mut next = next.lock().expect("chunk counter shouldn't be poisoned");
+                *next += 1;
+                *next - 1
+            };
+            if chunk >= chunks {
+                return Some(sum);
+            }
+            // The last range starting at or before the chunk, skipping empty
+            // ranges that start at the same chunk.
+            let index = starts.partition_point(|&start| start <= chunk) - 1;
+            let range = ranges[index];
+            let low = range.low() + (chunk - starts[index]) * chunk_size;
+            let high = range.high().min(low.saturating_add(chunk_size - 1));
+            sum = sum.checked_add(IdRange::new(low, high).sum_invalid_ids(is_invalid)?)?;
+        }
+    };
+    thread::scope(|scope| {
+        let handles: Vec<_> = (1..threads.get()).map(|_| scope.spawn(worker)).collect();
+        let own = worker();
+        // Every partial sum is at most the total, so adding them in any order
+        // overflows exactly when the total does.
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("scan thread shouldn't panic"))
+            .chain([own])
+            .try_fold(0u128, |total, sum| total.checked_add(sum?))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::{Day2, is_invalid_id_part1, is_invalid_id_part2};
+
+    fn sequential(ranges: &[IdRange], is_invalid: fn(u128) -> bool) -> Option<u128> {
+        ranges.iter().try_fold(0u128, |sum, range| {
+            sum.checked_add(range.sum_invalid_ids(is_invalid)?)
+        })
+    }
+
+    #[test]
+    fn test_matches_sequential() {
+        let ranges = Day2::parse(aoc_common::example_file!("test_input.txt")).unwrap();
+        let mut with_edges = ranges.clone();
+        with_edges.extend([
+            IdRange::new(30, 10),
+            IdRange::new(7, 7),
+            IdRange::new(1, 1200),
+        ]);
+        for ranges in [&ranges, &with_edges] {
+            for is_invalid in [is_invalid_id_part1, is_invalid_id_part2] {
+                let expected = sequential(ranges, is_invalid);
+                for threads in [1, 2, 3, 8] {
+                    let threads = NonZeroUsize::new(threads).unwrap();
+                    for chunk_size in [1, 7, 1000, CHUNK_SIZE] {
+                        assert_eq!(
+                            scan_in_chunks(ranges, threads, chunk_size, &is_invalid),
+                            expected,
+                            "{threads} threads, chunks of {chunk_size}"
+                        );
+                    }
+                }
+            }
+        }
+        assert_eq!(
+            scan_sum(&ranges, NonZeroUsize::new(4).unwrap(), is_invalid_id_part2),
+            Day2::part2(&ranges).total()
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let ranges = [IdRange::new(u128::MAX - 2, u128::MAX)];
+        let threads = NonZeroUsize::new(2).unwrap();
+        assert_eq!(scan_in_chunks(&ranges, threads, 1, &|_| true), None);
+        assert_eq!(
+            scan_in_chunks(&ranges, threads, 1, &|id| id == u128::MAX),
+            Some(u128::MAX)
+        );
+    }
+}
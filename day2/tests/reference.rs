@@ -0,0 +1,95 @@
+//! Compares the invalid ID sums against checking every ID for repeated blocks.
+
+use aoc_common::Solution;
+use std::{collections::BTreeSet, num::NonZeroUsize};
+
+use day2::{Day2, IdSum, Reversed, Rule};
+use proptest::prelude::*;
+
+/// Whether `id` is some block of digits repeated `times` times.
+fn repeats(id: u64, times: usize) -> bool {
+    let digits = id.to_string();
+    digits.len().is_multiple_of(times) && digits == digits[..digits.len() / times].repeat(times)
+}
+
+fn reference(ranges: &[(u64, u64)], is_invalid: impl Fn(u64) -> bool) -> u128 {
+    ranges
+        .iter()
+        .flat_map(|&(low, high)| low..=high)
+        .filter(|&id| is_invalid(id))
+        .map(u128::from)
+        .sum()
+}
+
+fn ranges() -> impl Strategy<Value = Vec<(u64, u64)>> {
+    prop::collection::vec(
+        (1..2_000_000u64, 0..1_000u64).prop_map(|(low, len)| (low, low + len)),
+        1..6,
+    )
+}
+
+fn render(ranges: &[(u64, u64)]) -> String {
+    ranges
+        .iter()
+        .map(|(low, high)| format!("{low}-{high}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn rules() -> impl Strategy<Value = Rule> {
+    (
+        prop::sample::select(Rule::BASES.to_vec()),
+        2..5u32,
+        prop::option::of(0..3u32),
+        any::<bool>(),
+    )
+        .prop_map(|(base, min, extra, leading_zeros)| {
+            Rule::new(base, min, extra.map(|extra| min + extra), leading_zeros).unwrap()
+        })
+}
+
+proptest! {
+    #[test]
+    fn matches_reference(ranges in ranges()) {
+        let input = Day2::parse(&render(&ranges)).unwrap();
+        prop_assert_eq!(Day2::part1(&input), IdSum::Total(reference(&ranges, |id| repeats(id, 2))));
+        prop_assert_eq!(
+            Day2::part2(&input),
+            IdSum::Total(reference(&ranges, |id| (2..=20).any(|times| repeats(id, times))))
+        );
+    }
+
+    #[test]
+    fn rules_match_checking_every_id(rule in rules(), ranges in ranges()) {
+        let sum = day2::sum_by_rule(&Day2::parse(&render(&ranges)).unwrap(), &rule);
+        prop_assert_eq!(sum, Some(reference(&ranges, |id| rule.is_invalid(u128::from(id)))));
+    }
+
+    #[test]
+    fn normalizing_counts_each_id_once(ranges in ranges(), flips in prop::collection::vec(any::<bool>(), 6)) {
+        // Some ranges written backwards, which swapping should undo.
+        let written: Vec<(u64, u64)> = ranges
+            .iter()
+            .zip(&flips)
+            .map(|(&(low, high), &flip)| if flip { (high, low) } else { (low, high) })
+            .collect();
+        let input = Day2::parse(&render(&written)).unwrap();
+        let normalized = day2::normalize(&input, Reversed::Swap).unwrap();
+        let ids: BTreeSet<u64> = ranges.iter().flat_map(|&(low, high)| low..=high).collect();
+        let once = |is_invalid: fn(u128) -> bool| -> u128 {
+            ids.iter().map(|&id| u128::from(id)).filter(|&id| is_invalid(id)).sum()
+        };
+        prop_assert_eq!(Day2::part1(&normalized.ranges), IdSum::Total(once(day2::is_invalid_id_part1)));
+        prop_assert_eq!(Day2::part2(&normalized.ranges), IdSum::Total(once(day2::is_invalid_id_part2)));
+        let covered: u128 = normalized.ranges.iter().map(|range| range.high() - range.low() + 1).sum();
+        prop_assert_eq!(covered, ids.len() as u128);
+    }
+
+    #[test]
+    fn scanning_matches_arithmetic(ranges in ranges(), threads in 1..6usize) {
+        let input = Day2::parse(&render(&ranges)).unwrap();
+        let threads = NonZeroUsize::new(threads).unwrap();
+        prop_assert_eq!(day2::scan_sum(&input, threads, day2::is_invalid_id_part1), Day2::part1(&input).total());
+        prop_assert_eq!(day2::scan_sum(&input, threads, day2::is_invalid_id_part2), Day2::part2(&input).total());
+    }
+}
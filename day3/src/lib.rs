@@ -0,0 +1,147 @@
+use std::io::{self, Write};
+
+use aoc_common::{
+    ParseError, Solution,
+    generate::{Rng, Size},
+};
+
+fn get_bank_joltage_part1(bank: &[u8]) -> u64 {
+    let (max, max_index) = get_max_joltage(&bank[0..bank.len() - 1]);
+    let (second_max, _) = get_max_joltage(&bank[max_index + 1..]);
+
+    format!("{max}{second_max}")
+        .parse()
+        .expect("should be number")
+}
+
+const MAX_JOLTAGE_DIGITS: usize = 12;
+
+fn get_bank_joltage_part2(bank: &[u8]) -> u64 {
+    let mut total_joltage = String::with_capacity(MAX_JOLTAGE_DIGITS);
+    let mut start = 0;
+    let mut end = bank.len() - MAX_JOLTAGE_DIGITS;
+    while total_joltage.len() != MAX_JOLTAGE_DIGITS {
+        let (joltage, index) = get_max_joltage(&bank[start..=end]);
+        total_joltage.push_str(&joltage.to_string());
+        start = (index + start) + 1;
+        end = bank.len() - (MAX_JOLTAGE_DIGITS - total_joltage.len());
+    }
+    total_joltage.parse().expect("should be number")
+}
+
+fn get_max_joltage(bank: &[u8]) -> (u8, usize) {
+    let mut max = bank[0];
+    let mut max_index = 0;
+    (0..bank.len()).for_each(|i| {
+        if bank[i] > max {
+            max = bank[i];
+            max_index = i;
+        }
+    });
+    (max, max_index)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u8>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc_common::lines(input)
+            .map(|line| {
+                if line.len() < MAX_JOLTAGE_DIGITS {
+                    return Err(ParseError::new(
+                        Self::DAY,
+                        input,
+                        line,
+                        format!("bank should have at least {MAX_JOLTAGE_DIGITS} batteries"),
+                    ));
+                }
+                line.char_indices()
+                    .map(|(i, digit)| {
+                        let battery = digit.to_digit(10).ok_or_else(|| {
+                            ParseError::new(
+                                Self::DAY,
+                                input,
+                                &line[i..i + digit.len_utf8()],
+                                format!("invalid battery '{digit}', expected a digit"),
+                            )
+                        })?;
+                        Ok(battery as u8)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(battery_banks: &Self::Input) -> u64 {
+        battery_banks
+            .iter()
+            .map(|bank| get_bank_joltage_part1(bank))
+            .sum()
+    }
+
+    fn part2(battery_banks: &Self::Input) -> u64 {
+        battery_banks
+            .iter()
+            .map(|bank| get_bank_joltage_part2(bank))
+            .sum()
+    }
+}
+
+/// Writes `size.count` banks of `size.width` batteries, 100 by default and never
+/// fewer than the twelve part 2 turns on.
+pub fn generate(out: &mut dyn Write, rng: &mut Rng, size: Size) -> io::Result<()> {
+    let width = size.width.unwrap_or(100).max(MAX_JOLTAGE_DIGITS);
+    for _ in 0..size.count {
+        let bank: Vec<u8> = (0..width).map(|_| b'0' + rng.range(1..=9) as u8).collect();
+        out.write_all(&bank)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+aoc_common::examples! {
+    Day3 {
+        example_part1: example_file!("test_input.txt"), part1 => 357,
+        example_part2: example_file!("test_input.txt"), part2 => 3121910778619,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_bank_joltage_part2() {
+        assert_eq!(
+            get_bank_joltage_part2(&[9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1]),
+            987654321111
+        );
+        assert_eq!(
+            get_bank_joltage_part2(&[8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9]),
+            811111111119
+        );
+        assert_eq!(
+            get_bank_joltage_part2(&[2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8]),
+            434234234278
+        );
+        assert_eq!(
+            get_bank_joltage_part2(&[8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1]),
+            888911112111
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day3::parse("987654321111111\n8111111x1111119\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 8, "x"));
+
+        let err = Day3::parse("987654321111111\n1234\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "1234"));
+    }
+}
@@ -0,0 +1,33 @@
+//! Compares the joltage selection against trying every choice of batteries.
+
+use aoc_common::Solution;
+use day3::Day3;
+use proptest::prelude::*;
+
+/// The largest number made of `count` batteries from `bank`, kept in order.
+fn best(bank: &[u8], count: u32) -> u64 {
+    if count == 0 {
+        return 0;
+    }
+    (0..=bank.len() - count as usize)
+        .map(|i| bank[i] as u64 * 10u64.pow(count - 1) + best(&bank[i + 1..], count - 1))
+        .max()
+        .expect("bank should have enough batteries")
+}
+
+fn banks() -> impl Strategy<Value = Vec<Vec<u8>>> {
+    prop::collection::vec(prop::collection::vec(0..=9u8, 12..=16), 1..5)
+}
+
+proptest! {
+    #[test]
+    fn matches_reference(banks in banks()) {
+        let text: String = banks
+            .iter()
+            .map(|bank| bank.iter().map(|battery| format!("{battery}")).collect::<String>() + "\n")
+            .collect();
+        let input = Day3::parse(&text).unwrap();
+        prop_assert_eq!(Day3::part1(&input), banks.iter().map(|bank| best(bank, 2)).sum::<u64>());
+        prop_assert_eq!(Day3::part2(&input), banks.iter().map(|bank| best(bank, 12)).sum::<u64>());
+    }
+}
@@ -0,0 +1,172 @@
+use std::io::{self, Write};
+
+use aoc_common::{
+    ParseError, Solution,
+    generate::{Rng, Size},
+};
+
+pub type SlotGrid = Vec<Vec<Slot>>;
+
+#[derive(Debug, Clone)]
+pub enum Slot {
+    Roll,
+    Empty,
+}
+
+impl TryFrom<char> for Slot {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '@' => Ok(Slot::Roll),
+            '.' => Ok(Slot::Empty),
+            _ => Err(format!("invalid slot '{value}', expected '@' or '.'")),
+        }
+    }
+}
+
+impl Slot {
+    pub fn is_roll(&self) -> bool {
+        matches!(self, Slot::Roll)
+    }
+}
+
+fn count_surrounding_rolls(grid: &SlotGrid, row: usize, col: usize) -> u64 {
+    let row_offsets = [-1isize, 0, 1];
+    let col_offsets = [-1isize, 0, 1];
+    let mut count = 0;
+    for row_offset in row_offsets {
+        for col_offset in col_offsets {
+            if row_offset == 0 && col_offset == 0 {
+                // don't count the current roll
+                continue;
+            }
+            let nearby_row = match row.checked_add_signed(row_offset) {
+                Some(index) => index,
+                None => continue,
+            };
+            let nearby_col = match col.checked_add_signed(col_offset) {
+                Some(index) => index,
+                None => continue,
+            };
+            let Some(slot) = get_slot(grid, nearby_row, nearby_col) else {
+                continue;
+            };
+            if slot.is_roll() {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn get_slot(grid: &SlotGrid, row: usize, col: usize) -> Option<&Slot> {
+    let row = grid.get(row)?;
+    row.get(col)
+}
+
+fn attempt_remove_roll(grid: &mut SlotGrid) -> bool {
+    for row in 0..grid.len() {
+        for col in 0..grid[row].len() {
+            if let Some(slot) = get_slot(grid, row, col)
+                && !slot.is_roll()
+            {
+                continue;
+            }
+            let nearby_rolls = count_surrounding_rolls(grid, row, col);
+            if nearby_rolls < 4 {
+                grid[row][col] = Slot::Empty;
+                return true;
+            }
+        }
+    }
+    false
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = SlotGrid;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc_common::lines(input)
+            .map(|line| {
+                line.char_indices()
+                    .map(|(i, char)| {
+                        char.try_into().map_err(|message| {
+                            ParseError::new(
+                                Self::DAY,
+                                input,
+                                &line[i..i + char.len_utf8()],
+                                message,
+                            )
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(grid: &Self::Input) -> u64 {
+        let mut open_rolls = 0;
+        for row in 0..grid.len() {
+            for col in 0..grid[row].len() {
+                if let Some(slot) = get_slot(grid, row, col)
+                    && !slot.is_roll()
+                {
+                    continue;
+                }
+
+                let nearby_rolls = count_surrounding_rolls(grid, row, col);
+                if nearby_rolls < 4 {
+                    open_rolls += 1;
+                }
+            }
+        }
+        open_rolls
+    }
+
+    fn part2(grid: &Self::Input) -> u64 {
+        let mut grid = grid.clone();
+        let mut total_removed = 0;
+        while attempt_remove_roll(&mut grid) {
+            total_removed += 1;
+        }
+        total_removed
+    }
+}
+
+/// Writes a grid of `size.count` rows and `size.width` columns (140 by default),
+/// a little over half of them rolls.
+pub fn generate(out: &mut dyn Write, rng: &mut Rng, size: Size) -> io::Result<()> {
+    let width = size.width.unwrap_or(140);
+    for _ in 0..size.count {
+        let row: String = (0..width)
+            .map(|_| if rng.chance(0.6) { '@' } else { '.' })
+            .collect();
+        writeln!(out, "{row}")?;
+    }
+    Ok(())
+}
+
+aoc_common::examples! {
+    Day4 {
+        example_part1: example_file!("test_input.txt"), part1 => 13,
+        example_part2: example_file!("test_input.txt"), part2 => 43,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day4::parse("..@@.\n@@#..\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "#"));
+    }
+}
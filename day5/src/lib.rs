@@ -0,0 +1,191 @@
+use std::{
+    cmp::{max, min},
+    io::{self, Write},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use aoc_common::{
+    ParseError, Solution,
+    generate::{Rng, Size},
+};
+
+pub type IngredientID = u64;
+
+#[derive(Debug)]
+pub struct KitchenState {
+    fresh_ranges: Vec<IngredientRange>,
+    available_ingredients: Vec<IngredientID>,
+}
+
+impl FromStr for KitchenState {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = aoc_common::sections(s);
+        let fresh_ranges = split
+            .next()
+            .unwrap_or_default()
+            .lines()
+            .map(|line| {
+                line.parse().map_err(|err: ParseIntError| {
+                    ParseError::new(Day5::DAY, s, line, format!("invalid range '{line}': {err}"))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let available_ingredients = split
+            .next()
+            .unwrap_or_default()
+            .lines()
+            .map(|line| {
+                line.parse::<u64>().map_err(|err| {
+                    ParseError::new(
+                        Day5::DAY,
+                        s,
+                        line,
+                        format!("invalid ingredient '{line}': {err}"),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(KitchenState {
+            fresh_ranges,
+            available_ingredients,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IngredientRange {
+    low: IngredientID,
+    high: IngredientID,
+}
+
+impl IngredientRange {
+    pub fn contains_ingredient(&self, id: IngredientID) -> bool {
+        (self.low..=self.high).contains(&id)
+    }
+
+    pub fn range_overlaps(&self, other: &IngredientRange) -> bool {
+        let contains_low = self.contains_ingredient(other.low);
+        let contains_high = self.contains_ingredient(other.high);
+        contains_low || contains_high
+    }
+
+    pub fn combine_range(&self, other: &IngredientRange) -> IngredientRange {
+        if !self.range_overlaps(other) && !other.range_overlaps(self) {
+            panic!("tried to combine bad ranges");
+        }
+        IngredientRange {
+            low: min(self.low, other.low),
+            high: max(self.high, other.high),
+        }
+    }
+
+    pub fn range_size(&self) -> u64 {
+        self.high - self.low + 1
+    }
+}
+
+impl FromStr for IngredientRange {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split('-');
+        let low = split.next().unwrap_or_default().parse()?;
+        let high = split.next().unwrap_or_default().parse()?;
+        Ok(IngredientRange { low, high })
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = KitchenState;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(kitchen_state: &Self::Input) -> u64 {
+        kitchen_state
+            .available_ingredients
+            .iter()
+            .filter(|id| {
+                kitchen_state
+                    .fresh_ranges
+                    .iter()
+                    .any(|range| range.contains_ingredient(**id))
+            })
+            .count() as u64
+    }
+
+    fn part2(kitchen_state: &Self::Input) -> u64 {
+        let mut fresh_ranges = kitchen_state.fresh_ranges.clone();
+        fresh_ranges.sort_unstable_by_key(|r| r.low);
+        let mut ranges: Vec<IngredientRange> = vec![];
+        for kitchen_range in &fresh_ranges {
+            let mut already_in_ranges = false;
+            for range in ranges.iter_mut() {
+                if range.range_overlaps(kitchen_range) {
+                    already_in_ranges = true;
+                    *range = range.combine_range(kitchen_range);
+                    break;
+                }
+            }
+            if !already_in_ranges {
+                ranges.push(kitchen_range.clone());
+            }
+        }
+        ranges.iter().map(|r| r.range_size()).sum()
+    }
+}
+
+/// Writes `size.count` fresh ranges followed by `size.count` available
+/// ingredients, about half of them inside some range, with IDs up to 500
+/// trillion like the real input.
+pub fn generate(out: &mut dyn Write, rng: &mut Rng, size: Size) -> io::Result<()> {
+    const MAX_ID: IngredientID = 500_000_000_000_000;
+    let ranges: Vec<(IngredientID, IngredientID)> = (0..size.count)
+        .map(|_| {
+            let low = rng.range(1..=MAX_ID);
+            (low, low + rng.below(MAX_ID / 100))
+        })
+        .collect();
+    for (low, high) in &ranges {
+        writeln!(out, "{low}-{high}")?;
+    }
+    writeln!(out)?;
+    for _ in 0..size.count {
+        let id = if rng.chance(0.5) {
+            let &(low, high) = rng.choose(&ranges);
+            rng.range(low..=high)
+        } else {
+            rng.range(1..=MAX_ID)
+        };
+        writeln!(out, "{id}")?;
+    }
+    Ok(())
+}
+
+aoc_common::examples! {
+    Day5 {
+        example_part1: example_file!("test_input.txt"), part1 => 3,
+        example_part2: example_file!("test_input.txt"), part2 => 14,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day5::parse("3-5\n10-14\n\n1\n5x\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (5, 1, "5x"));
+    }
+}
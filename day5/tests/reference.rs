@@ -0,0 +1,35 @@
+//! Compares the fresh ingredient counts against checking every ingredient ID.
+
+use std::collections::BTreeSet;
+
+use aoc_common::Solution;
+use day5::Day5;
+use proptest::prelude::*;
+
+fn is_fresh(ranges: &[(u64, u64)], id: u64) -> bool {
+    ranges.iter().any(|&(low, high)| low <= id && id <= high)
+}
+
+fn ranges() -> impl Strategy<Value = Vec<(u64, u64)>> {
+    prop::collection::vec(
+        (0..500u64, 0..60u64).prop_map(|(low, len)| (low, low + len)),
+        1..12,
+    )
+}
+
+proptest! {
+    #[test]
+    fn matches_reference(
+        ranges in ranges(),
+        ingredients in prop::collection::vec(0..600u64, 0..20),
+    ) {
+        let fresh: String = ranges.iter().map(|(low, high)| format!("{low}-{high}\n")).collect();
+        let available: String = ingredients.iter().map(|id| format!("{id}\n")).collect();
+        let input = Day5::parse(&format!("{fresh}\n{available}")).unwrap();
+
+        let fresh_available = ingredients.iter().filter(|&&id| is_fresh(&ranges, id)).count();
+        let all_fresh: BTreeSet<u64> = ranges.iter().flat_map(|&(low, high)| low..=high).collect();
+        prop_assert_eq!(Day5::part1(&input), fresh_available as u64);
+        prop_assert_eq!(Day5::part2(&input), all_fresh.len() as u64);
+    }
+}
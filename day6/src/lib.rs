@@ -0,0 +1,274 @@
+use std::{
+    io::{self, Write},
+    str::FromStr,
+};
+
+use aoc_common::{
+    ParseError, Solution,
+    generate::{Rng, Size},
+};
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+impl FromStr for Operator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Operator::Add),
+            "*" => Ok(Operator::Multiply),
+            _ => Err(format!("invalid operator found: '{s}'")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Problem {
+    numbers: Vec<u64>,
+    operator: Operator,
+}
+
+impl Problem {
+    pub fn solve(&self) -> u64 {
+        match self.operator {
+            Operator::Add => self.numbers.iter().sum(),
+            Operator::Multiply => self.numbers.iter().product(),
+        }
+    }
+}
+
+fn split_worksheet(text: &str) -> Result<(Vec<&str>, &str), ParseError> {
+    let mut lines: Vec<&str> = aoc_common::lines(text).collect();
+    let operator_line = lines.pop().ok_or_else(|| {
+        ParseError::new(
+            Day6::DAY,
+            text,
+            &text[text.len()..],
+            "expected an operator line",
+        )
+    })?;
+    Ok((lines, operator_line))
+}
+
+fn parse_operators(text: &str, operator_line: &str) -> Result<Vec<Operator>, ParseError> {
+    operator_line
+        .split_whitespace()
+        .map(|op_str| {
+            op_str
+                .parse()
+                .map_err(|message| ParseError::new(Day6::DAY, text, op_str, message))
+        })
+        .collect()
+}
+
+fn column_span(line: &str, col: usize) -> &str {
+    match line.char_indices().nth(col) {
+        Some((i, char)) => &line[i..i + char.len_utf8()],
+        None => &line[line.len()..],
+    }
+}
+
+fn parse_problems(text: &str) -> Result<Vec<Problem>, ParseError> {
+    let (number_lines, operator_line) = split_worksheet(text)?;
+    let operators = parse_operators(text, operator_line)?;
+    let number_rows: Vec<Vec<u64>> = number_lines
+        .iter()
+        .map(|line| {
+            let row = line
+                .split_ascii_whitespace()
+                .map(|num| {
+                    num.parse().map_err(|err| {
+                        ParseError::new(
+                            Day6::DAY,
+                            text,
+                            num,
+                            format!("invalid number '{num}': {err}"),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<u64>, _>>()?;
+            if row.len() != operators.len() {
+                return Err(ParseError::new(
+                    Day6::DAY,
+                    text,
+                    line,
+                    format!("expected {} numbers, found {}", operators.len(), row.len()),
+                ));
+            }
+            Ok(row)
+        })
+        .collect::<Result<_, _>>()?;
+    let mut problems = Vec::new();
+    for i in 0..operators.len() {
+        let numbers = number_rows.iter().map(|row| row[i]).collect();
+        let operator = operators[i];
+        problems.push(Problem { numbers, operator });
+    }
+    Ok(problems)
+}
+
+fn parse_problems_pt2(text: &str) -> Result<Vec<Problem>, ParseError> {
+    let (number_lines, operator_line) = split_worksheet(text)?;
+    let operators: Vec<Operator> = parse_operators(text, operator_line)?
+        .into_iter()
+        .rev()
+        .collect();
+
+    let number_matrix: Vec<Vec<char>> = number_lines
+        .iter()
+        .map(|line| line.chars().collect())
+        .collect();
+    let width = number_matrix.iter().map(Vec::len).max().unwrap_or_default();
+    let is_blank = |row: usize, col: usize| {
+        number_matrix[row]
+            .get(col)
+            .is_none_or(|char| char.is_whitespace())
+    };
+
+    let mut number_rows: Vec<Vec<u64>> = Vec::new();
+    let mut current_problem: Vec<u64> = Vec::new();
+    for col in (0..width).rev() {
+        let new_problem = (0..number_matrix.len()).all(|row| is_blank(row, col));
+        if new_problem {
+            number_rows.push(current_problem.clone());
+            current_problem.clear();
+            continue;
+        }
+        let mut number_str = String::new();
+        let mut first_row = None;
+        (0..number_matrix.len()).for_each(|row| {
+            if is_blank(row, col) {
+                return;
+            }
+            first_row.get_or_insert(row);
+            number_str.push(number_matrix[row][col]);
+        });
+        let number = number_str.parse().map_err(|err| {
+            let line = number_lines[first_row.unwrap_or_default()];
+            ParseError::new(
+                Day6::DAY,
+                text,
+                column_span(line, col),
+                format!("invalid number '{number_str}': {err}"),
+            )
+        })?;
+        current_problem.push(number);
+    }
+    number_rows.push(current_problem);
+
+    if number_rows.len() != operators.len() {
+        return Err(ParseError::new(
+            Day6::DAY,
+            text,
+            operator_line,
+            format!(
+                "expected {} operators, found {}",
+                number_rows.len(),
+                operators.len()
+            ),
+        ));
+    }
+    Ok(operators
+        .iter()
+        .enumerate()
+        .map(|(i, op)| Problem {
+            operator: *op,
+            numbers: number_rows[i].clone(),
+        })
+        .collect())
+}
+
+#[derive(Debug)]
+pub struct Worksheet {
+    problems: Vec<Problem>,
+    problems_pt2: Vec<Problem>,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Worksheet {
+            problems: parse_problems(input)?,
+            problems_pt2: parse_problems_pt2(input)?,
+        })
+    }
+
+    fn part1(worksheet: &Self::Input) -> u64 {
+        worksheet.problems.iter().map(|p| p.solve()).sum()
+    }
+
+    fn part2(worksheet: &Self::Input) -> u64 {
+        worksheet.problems_pt2.iter().map(|p| p.solve()).sum()
+    }
+}
+
+/// Writes `size.count` problems side by side, each with `size.width` numbers
+/// (4 by default) of up to four digits, aligned left or right within the problem.
+pub fn generate(out: &mut dyn Write, rng: &mut Rng, size: Size) -> io::Result<()> {
+    let rows = size.width.unwrap_or(4);
+    let mut lines = vec![String::new(); rows + 1];
+    for i in 0..size.count {
+        let numbers: Vec<String> = (0..rows)
+            .map(|_| {
+                let digits = rng.range(1..=4) as u32;
+                rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1)
+                    .to_string()
+            })
+            .collect();
+        let width = numbers.iter().map(String::len).max().unwrap_or(1);
+        let left_aligned = rng.chance(0.5);
+        for (line, number) in lines.iter_mut().zip(&numbers) {
+            if i > 0 {
+                line.push(' ');
+            }
+            if left_aligned {
+                line.push_str(&format!("{number:<width$}"));
+            } else {
+                line.push_str(&format!("{number:>width$}"));
+            }
+        }
+        let operator_line = &mut lines[rows];
+        if i > 0 {
+            operator_line.push(' ');
+        }
+        let operator = rng.choose(&['+', '*']);
+        operator_line.push_str(&format!("{operator:<width$}"));
+    }
+    for line in lines {
+        writeln!(out, "{line}")?;
+    }
+    Ok(())
+}
+
+aoc_common::examples! {
+    Day6 {
+        example_part1: example_file!("test_input.txt"), part1 => 4277556,
+        example_part2: example_file!("test_input.txt"), part2 => 3263827,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day6::parse("123 328\n 45 64\n*   -\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 5, "-"));
+        assert_eq!(err.message, "invalid operator found: '-'");
+
+        let err = Day6::parse("123 3x8\n 45 64\n*   +\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 5, "3x8"));
+    }
+}
@@ -0,0 +1,300 @@
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    str::FromStr,
+};
+
+use aoc_common::{
+    ParseError, Solution,
+    generate::{Rng, Size},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RoomItem {
+    Empty,
+    Start,
+    Beam,
+    Splitter,
+}
+
+impl TryFrom<char> for RoomItem {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(RoomItem::Empty),
+            'S' => Ok(RoomItem::Start),
+            '|' => Ok(RoomItem::Beam),
+            '^' => Ok(RoomItem::Splitter),
+            _ => Err(format!("got invalid room item char: {}", value)),
+        }
+    }
+}
+
+impl std::fmt::Display for RoomItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoomItem::Empty => write!(f, "."),
+            RoomItem::Start => write!(f, "S"),
+            RoomItem::Beam => write!(f, "|"),
+            RoomItem::Splitter => write!(f, "^"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Room {
+    grid: Vec<Vec<RoomItem>>,
+}
+
+impl std::fmt::Display for Room {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.grid.iter() {
+            for item in row {
+                write!(f, "{}", item)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Room {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Vec<Vec<RoomItem>> = aoc_common::lines(s)
+            .map(|line| {
+                line.char_indices()
+                    .map(|(i, char)| {
+                        char.try_into().map_err(|message| {
+                            ParseError::new(Day7::DAY, s, &line[i..i + char.len_utf8()], message)
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        if !grid.iter().flatten().any(|item| *item == RoomItem::Start) {
+            return Err(ParseError::new(
+                Day7::DAY,
+                s,
+                &s[s.len()..],
+                "room should have a start 'S'",
+            ));
+        }
+        Ok(Room { grid })
+    }
+}
+
+#[derive(Debug)]
+struct Simulator {
+    room: Room,
+    tick: usize,
+    splits: u64,
+}
+
+impl Simulator {
+    pub fn with_room(room: Room) -> Self {
+        Simulator {
+            room,
+            tick: 0,
+            splits: 0,
+        }
+    }
+
+    pub fn get_room_item(&self, row: usize, col: usize) -> Option<RoomItem> {
+        self.room.grid.get(row)?.get(col).copied()
+    }
+
+    pub fn set_room_item(&mut self, item: RoomItem, row: usize, col: usize) {
+        if row > self.room.grid.len() || col > self.room.grid[row].len() {
+            return;
+        }
+        self.room.grid[row][col] = item;
+    }
+
+    pub fn is_item(&self, item: RoomItem, row: usize, col: usize) -> bool {
+        if let Some(room_item) = self.get_room_item(row, col) {
+            return room_item == item;
+        }
+        false
+    }
+
+    pub fn get_start(&self) -> (usize, usize) {
+        for row in 0..self.room.grid.len() {
+            for col in 0..self.room.grid[row].len() {
+                if self.is_item(RoomItem::Start, row, col) {
+                    return (row, col);
+                }
+            }
+        }
+        unreachable!("room must have start")
+    }
+
+    pub fn beam_can_go(&self, row: usize, col: usize) -> bool {
+        if let Some(item) = self.get_room_item(row, col) {
+            return match item {
+                RoomItem::Empty => true,
+                RoomItem::Start => false,
+                RoomItem::Beam => false,
+                RoomItem::Splitter => true,
+            };
+        }
+        false
+    }
+
+    pub fn sim_over(&self) -> bool {
+        if self.tick == 0 {
+            return false;
+        }
+        for row in self.tick..self.room.grid.len() {
+            for col in 0..self.room.grid[row].len() {
+                if self.is_item(RoomItem::Beam, row, col) && self.beam_can_go(row + 1, col) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    pub fn simulate_tick_pt1(&mut self) {
+        for row in (self.tick..self.room.grid.len()).rev() {
+            for col in 0..self.room.grid[row].len() {
+                let Some(item) = self.get_room_item(row, col) else {
+                    continue;
+                };
+                if matches!(item, RoomItem::Beam) || matches!(item, RoomItem::Start) {
+                    self.expand_beam_pt1(row, col);
+                }
+            }
+        }
+        self.tick += 1;
+    }
+
+    fn expand_beam_pt1(&mut self, row: usize, col: usize) {
+        if let Some(item) = self.get_room_item(row + 1, col) {
+            match item {
+                RoomItem::Empty => self.set_room_item(RoomItem::Beam, row + 1, col),
+                RoomItem::Start => {
+                    self.set_room_item(RoomItem::Beam, row + 1, col);
+                }
+                RoomItem::Beam => {}
+                RoomItem::Splitter => {
+                    self.set_room_item(RoomItem::Beam, row + 1, col + 1);
+                    self.set_room_item(RoomItem::Beam, row + 1, col - 1);
+                    self.splits += 1;
+                }
+            }
+        }
+    }
+
+    pub fn simulate_timelines(&mut self) -> u64 {
+        let mut cache: HashMap<(usize, usize), u64> = HashMap::new();
+        let (row, col) = self.get_start();
+        self.calculate_timeline_count(&mut cache, row, col)
+    }
+
+    pub fn calculate_timeline_count(
+        &mut self,
+        cache: &mut HashMap<(usize, usize), u64>,
+        row: usize,
+        col: usize,
+    ) -> u64 {
+        if let Some(timelines) = cache.get(&(row, col)) {
+            return *timelines;
+        }
+        let item = match self.get_room_item(row + 1, col) {
+            None => return 1,
+            Some(item) => item,
+        };
+        match item {
+            RoomItem::Empty | RoomItem::Start => {
+                let down = self.calculate_timeline_count(cache, row + 1, col);
+                cache.insert((row + 1, col), down);
+                down
+            }
+            RoomItem::Splitter => {
+                let left = self.calculate_timeline_count(cache, row + 1, col - 1);
+                cache.insert((row + 1, col - 1), left);
+                let right = self.calculate_timeline_count(cache, row + 1, col + 1);
+                cache.insert((row + 1, col + 1), right);
+                left + right
+            }
+            RoomItem::Beam => unreachable!("should be no beams in part2"),
+        }
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Room;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(room: &Self::Input) -> u64 {
+        let mut sim = Simulator::with_room(room.clone());
+        while !sim.sim_over() {
+            sim.simulate_tick_pt1();
+        }
+        sim.splits
+    }
+
+    fn part2(room: &Self::Input) -> u64 {
+        let mut sim = Simulator::with_room(room.clone());
+        sim.simulate_timelines()
+    }
+}
+
+/// Writes a room of `size.count` rows and `size.width` columns (141 by default),
+/// with the start centred on the first row and splitters on every other row,
+/// spreading out from the start like the real input.
+pub fn generate(out: &mut dyn Write, rng: &mut Rng, size: Size) -> io::Result<()> {
+    let width = size.width.unwrap_or(141).max(3);
+    let start = width / 2;
+    for row in 0..size.count {
+        let mut line = vec![b'.'; width];
+        if row == 0 {
+            line[start] = b'S';
+        } else if row % 2 == 0 {
+            let reach = row / 2 - 1;
+            let cols = start.saturating_sub(reach).max(1)..=(start + reach).min(width - 2);
+            for col in cols.filter(|col| col % 2 == (start + reach) % 2) {
+                if rng.chance(0.6) {
+                    line[col] = b'^';
+                }
+            }
+        }
+        out.write_all(&line)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+aoc_common::examples! {
+    Day7 {
+        example_part1: example_file!("test_input.txt"), part1 => 21,
+        example_part2: example_file!("test_input.txt"), part2 => 40,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day7::parse("..S..\n..^..\n.^.x.\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 4, "x"));
+        assert_eq!(err.message, "got invalid room item char: x");
+
+        let err = Day7::parse(".....\n..^..\n").unwrap_err();
+        assert_eq!(err.message, "room should have a start 'S'");
+    }
+}
@@ -0,0 +1,53 @@
+//! Compares the timeline count against following every path a particle can take.
+
+use aoc_common::Solution;
+use day7::Day7;
+use proptest::prelude::*;
+
+/// Walks down from `(row, col)`, taking both sides of every splitter.
+fn timelines(grid: &[Vec<char>], row: usize, col: usize) -> u64 {
+    match grid.get(row + 1) {
+        None => 1,
+        Some(below) if below[col] == '^' => {
+            timelines(grid, row + 1, col - 1) + timelines(grid, row + 1, col + 1)
+        }
+        Some(_) => timelines(grid, row + 1, col),
+    }
+}
+
+/// A start row followed by alternating empty and splitter rows, keeping splitters
+/// off the edges so every split stays inside the room.
+fn rooms() -> impl Strategy<Value = Vec<Vec<char>>> {
+    (3..12usize, 1..7usize).prop_flat_map(|(width, splitter_rows)| {
+        let start = 1..width - 1;
+        let rows = prop::collection::vec(
+            prop::collection::vec(prop::bool::weighted(0.4), width - 2),
+            splitter_rows,
+        );
+        (start, rows).prop_map(move |(start, rows)| {
+            let mut grid = vec![vec!['.'; width]];
+            grid[0][start] = 'S';
+            for splitters in rows {
+                grid.push(vec!['.'; width]);
+                let mut row = vec!['.'; width];
+                for (col, splitter) in splitters.into_iter().enumerate() {
+                    if splitter {
+                        row[col + 1] = '^';
+                    }
+                }
+                grid.push(row);
+            }
+            grid
+        })
+    })
+}
+
+proptest! {
+    #[test]
+    fn matches_reference(grid in rooms()) {
+        let text: String = grid.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+        let input = Day7::parse(&text).unwrap();
+        let start = grid[0].iter().position(|&item| item == 'S').unwrap();
+        prop_assert_eq!(Day7::part2(&input), timelines(&grid, 0, start));
+    }
+}